@@ -0,0 +1,282 @@
+use crate::parse::ParseError;
+use std::{
+    fmt::Display,
+    iter::FusedIterator,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: i32,
+    height: i32,
+    elems: Vec<T>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Pos {
+    pub fn new(x: i32, y: i32) -> Pos {
+        Pos { x, y }
+    }
+
+    pub fn adv<D: Dir>(&self, d: D) -> Pos {
+        let v = d.vector();
+        Pos {
+            x: self.x + v.0,
+            y: self.y + v.1,
+        }
+    }
+
+    pub fn manhattan(&self, other: Pos) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+}
+
+impl<I> From<(I, I)> for Pos
+where
+    I: Into<i32>,
+{
+    fn from(t: (I, I)) -> Pos {
+        Pos {
+            x: t.0.into(),
+            y: t.1.into(),
+        }
+    }
+}
+
+pub struct Neighbours<'a, T, DIt> {
+    grid: &'a Grid<T>,
+    di: DIt,
+    pos: Pos,
+}
+
+impl<T, D, DIt> Iterator for Neighbours<'_, T, DIt>
+where
+    D: Dir,
+    DIt: Iterator<Item = D>,
+{
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.di.by_ref().find_map(|d| self.grid.adv(self.pos, d))
+    }
+}
+
+// Doesn't borrow the grid, so it's fine to modify the grid while iterating.
+pub struct GridIter {
+    width: i32,
+    len: i32,
+    idx: i32,
+}
+
+impl Iterator for GridIter {
+    type Item = Pos;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.len {
+            return None;
+        }
+        let pos = Pos {
+            x: self.idx % self.width,
+            y: self.idx / self.width,
+        };
+        self.idx += 1;
+        Some(pos)
+    }
+}
+
+impl FusedIterator for GridIter {}
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    pub fn new(width: usize, height: usize, initial: T) -> Grid<T> {
+        Grid {
+            width: width as i32,
+            height: height as i32,
+            elems: vec![initial; width * height],
+        }
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.elems.fill(value);
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new_with<F>(width: usize, height: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut elems = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                elems.push(f(x, y));
+            }
+        }
+        Grid {
+            width: width as i32,
+            height: height as i32,
+            elems,
+        }
+    }
+
+    pub fn map<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            elems: self.elems.iter().map(&mut f).collect(),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height as usize
+    }
+
+    pub fn width(&self) -> usize {
+        self.width as usize
+    }
+
+    pub fn iter(&self) -> GridIter {
+        GridIter {
+            width: self.width,
+            len: self.width * self.height,
+            idx: 0,
+        }
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.in_bounds(pos)
+            .then(|| &self.elems[(pos.x + pos.y * self.width) as usize])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.in_bounds(pos)
+            .then(|| &mut self.elems[(pos.x + pos.y * self.width) as usize])
+    }
+
+    pub fn adv<D: Dir>(&self, pos: Pos, d: D) -> Option<Pos> {
+        let npos = pos.adv(d);
+        self.in_bounds(npos).then_some(npos)
+    }
+
+    pub fn neighbours<Dit>(&self, di: Dit, pos: Pos) -> Neighbours<'_, T, Dit> {
+        Neighbours {
+            grid: self,
+            di,
+            pos,
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: PartialEq,
+{
+    pub fn find(&self, value: &T) -> Option<Pos> {
+        self.iter().find(|pos| self[*pos] == *value)
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows: Vec<&str> = s.trim().lines().collect();
+        let width = rows.first().map_or(0, |r| r.len());
+        if width == 0 {
+            return Err(ParseError::new(s, s.trim(), "grid"));
+        }
+        if let Some(r) = rows.iter().find(|r| r.len() != width) {
+            return Err(ParseError::new(s, r, format!("row of width {width}")));
+        }
+        Ok(Grid::new_with(width, rows.len(), |x, y| {
+            rows[y].as_bytes()[x]
+        }))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos).unwrap()
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos).unwrap()
+    }
+}
+
+impl Display for Grid<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for pos in self.iter() {
+            let c = char::from_u32(self[pos] as u32).ok_or(std::fmt::Error)?;
+            if pos.x == 0 && pos.y != 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Dir {
+    fn vector(&self) -> (i32, i32);
+}
+
+impl Dir for (i32, i32) {
+    fn vector(&self) -> (i32, i32) {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn dimensions() {
+        let grid: Grid<u8> = "abc\ndef\n".parse().unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Pos::new(2, 1)], b'f');
+        assert_eq!(grid.get(Pos::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn ragged() {
+        let err = "abc\nde".parse::<Grid<u8>>().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "row of width 3");
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        let mut n: Vec<Pos> = grid
+            .neighbours(
+                [(-1, 0), (1, 0), (0, -1), (0, 1)].into_iter(),
+                Pos::new(0, 0),
+            )
+            .collect();
+        n.sort();
+        assert_eq!(n, vec![Pos::new(0, 1), Pos::new(1, 0)]);
+    }
+}
@@ -0,0 +1,6 @@
+pub mod bitset;
+pub mod grid;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod unionfind;
@@ -0,0 +1,137 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    pub day: Option<u32>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+// Byte offset of `at` in `input`, `at` is expected to be a slice of `input`,
+// but anything else is just reported at the end of the input.
+fn offset_in(input: &str, at: &str) -> usize {
+    let start = input.as_ptr() as usize;
+    let pos = at.as_ptr() as usize;
+    if pos >= start && pos <= start + input.len() {
+        pos - start
+    } else {
+        input.len()
+    }
+}
+
+fn describe(input: &str, offset: usize, at: &str) -> String {
+    let token = at.lines().next().unwrap_or("");
+    if token.chars().count() > 20 {
+        let token: String = token.chars().take(20).collect();
+        return format!("{:?}...", token);
+    }
+    if !token.is_empty() {
+        return format!("{:?}", token);
+    }
+    match input[offset..].chars().next() {
+        None => "end of input".to_string(),
+        Some('\n') => "end of line".to_string(),
+        Some(c) => format!("{:?}", c),
+    }
+}
+
+impl ParseError {
+    pub fn new(input: &str, at: &str, expected: impl Into<String>) -> ParseError {
+        let offset = offset_in(input, at);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        ParseError {
+            day: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            expected: expected.into(),
+            found: describe(input, offset, at),
+        }
+    }
+
+    // Error found when parsing `sub`, a slice of the bigger `input`.
+    pub fn rebase(self, input: &str, sub: &str) -> ParseError {
+        let origin = ParseError::new(input, sub, "");
+        ParseError {
+            line: origin.line + self.line - 1,
+            column: if self.line == 1 {
+                origin.column + self.column - 1
+            } else {
+                self.column
+            },
+            ..self
+        }
+    }
+
+    pub fn with_day(self, day: u32) -> ParseError {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+pub fn num<T: FromStr>(input: &str, s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(input, s, "number"))
+}
+
+pub fn split_once<'a>(
+    input: &str,
+    s: &'a str,
+    sep: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(sep)
+        .ok_or_else(|| ParseError::new(input, &s[s.len()..], format!("{:?}", sep)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn location() {
+        let input = "12 34\n56 x7\n";
+        let e = num::<u32>(input, &input[9..11]).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert_eq!(e.found, "\"x7\"");
+        assert_eq!(
+            e.with_day(1).to_string(),
+            "day 1, line 2, column 4: expected number, found \"x7\""
+        );
+
+        let e = ParseError::new(input, &input[5..5], "digit");
+        assert_eq!((e.line, e.column), (1, 6));
+        assert_eq!(e.found, "end of line");
+
+        let e = ParseError::new(input, &input[input.len()..], "digit");
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(e.found, "end of input");
+    }
+
+    #[test]
+    fn rebase() {
+        let input = "ab\ncd ef\ngh";
+        let sub = &input[6..];
+        let e = ParseError::new(sub, &sub[1..], "x").rebase(input, sub);
+        assert_eq!((e.line, e.column), (2, 5));
+        let e = ParseError::new(sub, &sub[4..], "x").rebase(input, sub);
+        assert_eq!((e.line, e.column), (3, 2));
+    }
+}
@@ -0,0 +1,385 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! cost_int {
+    ($($t:ty),*) => {
+        $(impl Cost for $t {
+            const ZERO: $t = 0;
+        })*
+    };
+}
+
+cost_int!(i32, i64, u32, u64, usize);
+
+pub trait Graph {
+    type Node: Copy + Eq + Hash;
+    type Cost: Cost;
+
+    // Outgoing edges of `node` with their costs, bfs ignores the costs.
+    fn neighbours(&self, node: Self::Node) -> impl Iterator<Item = (Self::Node, Self::Cost)>;
+}
+
+// All the predecessors of the nodes on their shortest paths, only the start
+// has none. Cycles of zero cost edges would make it not a DAG, so these are
+// not supported.
+pub struct Dag<N> {
+    prev: HashMap<N, Vec<N>>,
+}
+
+impl<N> Dag<N>
+where
+    N: Copy + Eq + Hash,
+{
+    pub fn prev(&self, node: N) -> &[N] {
+        self.prev.get(&node).map_or(&[], |p| p.as_slice())
+    }
+
+    // Every node on any of the shortest paths to `ends`.
+    pub fn states(&self, ends: &[N]) -> HashSet<N> {
+        let mut states: HashSet<N> = ends.iter().copied().collect();
+        let mut queue: VecDeque<N> = ends.iter().copied().collect();
+        while let Some(node) = queue.pop_front() {
+            for p in self.prev(node) {
+                if states.insert(*p) {
+                    queue.push_back(*p);
+                }
+            }
+        }
+        states
+    }
+
+    // Number of distinct shortest paths to `ends`, saturating at u64::MAX.
+    pub fn count(&self, ends: &[N]) -> u64 {
+        let mut counts: HashMap<N, u64> = HashMap::new();
+        let mut stack: Vec<(N, bool)> = ends.iter().map(|n| (*n, false)).collect();
+        while let Some((node, expanded)) = stack.pop() {
+            if counts.contains_key(&node) {
+                continue;
+            }
+            let prev = self.prev(node);
+            if prev.is_empty() {
+                counts.insert(node, 1);
+            } else if expanded {
+                let count = prev
+                    .iter()
+                    .fold(0u64, |acc, p| acc.saturating_add(counts[p]));
+                counts.insert(node, count);
+            } else {
+                stack.push((node, true));
+                stack.extend(prev.iter().map(|p| (*p, false)));
+            }
+        }
+        ends.iter()
+            .fold(0u64, |acc, n| acc.saturating_add(counts[n]))
+    }
+
+    // Lazily enumerates the shortest paths to `ends`, from the start on.
+    pub fn paths<'a>(&'a self, ends: &'a [N]) -> DagPaths<'a, N> {
+        DagPaths {
+            dag: self,
+            ends: ends.iter(),
+            stack: Vec::new(),
+        }
+    }
+}
+
+// Depth first walk back from every end, with the index of the predecessor
+// to try next for every node on the current path.
+pub struct DagPaths<'a, N> {
+    dag: &'a Dag<N>,
+    ends: std::slice::Iter<'a, N>,
+    stack: Vec<(N, usize)>,
+}
+
+impl<N> Iterator for DagPaths<'_, N>
+where
+    N: Copy + Eq + Hash,
+{
+    type Item = Vec<N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some((node, idx)) = self.stack.last_mut() else {
+                self.stack.push((*self.ends.next()?, 0));
+                continue;
+            };
+            let prev = self.dag.prev(*node);
+            if prev.is_empty() {
+                let path = self.stack.iter().rev().map(|(n, _)| *n).collect();
+                self.stack.pop();
+                return Some(path);
+            }
+            if *idx == prev.len() {
+                self.stack.pop();
+                continue;
+            }
+            let next = prev[*idx];
+            *idx += 1;
+            self.stack.push((next, 0));
+        }
+    }
+}
+
+// Result of a search: the distance of every reached node and the DAG of the
+// shortest paths to them.
+pub struct Paths<N, C> {
+    dist: HashMap<N, C>,
+    dag: Dag<N>,
+    end: Option<C>,
+    ends: Vec<N>,
+}
+
+impl<N, C> Paths<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Cost,
+{
+    fn new(start: N) -> Paths<N, C> {
+        Paths {
+            dist: HashMap::from([(start, C::ZERO)]),
+            dag: Dag {
+                prev: HashMap::new(),
+            },
+            end: None,
+            ends: Vec::new(),
+        }
+    }
+
+    // True if `next` got a shorter distance and has to be (re)visited.
+    fn relax(&mut self, node: N, next: N, cost: C) -> bool {
+        match self.dist.entry(next) {
+            Entry::Occupied(mut e) if cost < *e.get() => {
+                e.insert(cost);
+                self.dag.prev.insert(next, vec![node]);
+                true
+            }
+            Entry::Occupied(e) => {
+                if cost == *e.get() {
+                    self.dag.prev.entry(next).or_default().push(node);
+                }
+                false
+            }
+            Entry::Vacant(e) => {
+                e.insert(cost);
+                self.dag.prev.insert(next, vec![node]);
+                true
+            }
+        }
+    }
+
+    // Goals are reached in order of their cost, only the cheapest are kept.
+    fn reach(&mut self, node: N, cost: C) {
+        if self.end.is_none() {
+            self.end = Some(cost);
+        }
+        if self.end == Some(cost) {
+            self.ends.push(node);
+        }
+    }
+
+    // Nodes costing the same as the found goal are not expanded any more,
+    // only other goals of the same cost are still collected.
+    fn done(&self, cost: C) -> bool {
+        self.end.is_some_and(|end| cost >= end)
+    }
+
+    pub fn dist(&self, node: N) -> Option<C> {
+        self.dist.get(&node).copied()
+    }
+
+    pub fn dag(&self) -> &Dag<N> {
+        &self.dag
+    }
+
+    // Cost of the cheapest goal, None if no goal was reachable.
+    pub fn end(&self) -> Option<C> {
+        self.end
+    }
+
+    // All the goals reachable for the cost of `end`.
+    pub fn ends(&self) -> &[N] {
+        &self.ends
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (N, C)> + '_ {
+        self.dist.iter().map(|(n, c)| (*n, *c))
+    }
+}
+
+pub fn bfs<G, F>(graph: &G, start: G::Node, mut goal: F) -> Paths<G::Node, usize>
+where
+    G: Graph,
+    F: FnMut(G::Node) -> bool,
+{
+    let mut paths = Paths::new(start);
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        let dist = paths.dist[&node];
+        if goal(node) {
+            paths.reach(node, dist);
+        }
+        if paths.done(dist) {
+            continue;
+        }
+        for (next, _) in graph.neighbours(node) {
+            if paths.relax(node, next, dist + 1) {
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+struct QueueEntry<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for QueueEntry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for QueueEntry<N, C> {}
+
+impl<N, C: Ord> PartialOrd for QueueEntry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Reversed, so the BinaryHeap pops the lowest estimate first.
+impl<N, C: Ord> Ord for QueueEntry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then(other.cost.cmp(&self.cost))
+    }
+}
+
+// `heuristic` has to be consistent (never decreasing by more than the edge
+// cost) for the distances and predecessors to be right.
+pub fn astar<G, F, H>(
+    graph: &G,
+    start: G::Node,
+    mut goal: F,
+    heuristic: H,
+) -> Paths<G::Node, G::Cost>
+where
+    G: Graph,
+    F: FnMut(G::Node) -> bool,
+    H: Fn(G::Node) -> G::Cost,
+{
+    let mut paths = Paths::new(start);
+    let mut queue = BinaryHeap::from([QueueEntry {
+        estimate: heuristic(start),
+        cost: G::Cost::ZERO,
+        node: start,
+    }]);
+    while let Some(QueueEntry {
+        estimate,
+        cost,
+        node,
+    }) = queue.pop()
+    {
+        if paths.end.is_some_and(|end| estimate > end) {
+            break;
+        }
+        if cost > paths.dist[&node] {
+            continue;
+        }
+        if goal(node) {
+            paths.reach(node, cost);
+        }
+        if paths.done(cost) {
+            continue;
+        }
+        for (next, edge) in graph.neighbours(node) {
+            let next_cost = cost + edge;
+            if paths.relax(node, next, next_cost) {
+                queue.push(QueueEntry {
+                    estimate: next_cost + heuristic(next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    paths
+}
+
+pub fn dijkstra<G, F>(graph: &G, start: G::Node, goal: F) -> Paths<G::Node, G::Cost>
+where
+    G: Graph,
+    F: FnMut(G::Node) -> bool,
+{
+    astar(graph, start, goal, |_| G::Cost::ZERO)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // 0 -1-> 1 -1-> 3, 0 -2-> 2 -0-> 3, 3 -5-> 4
+    struct Small;
+
+    impl Graph for Small {
+        type Node = u32;
+        type Cost = u32;
+
+        fn neighbours(&self, node: u32) -> impl Iterator<Item = (u32, u32)> {
+            let edges: &[(u32, u32)] = match node {
+                0 => &[(1, 1), (2, 2)],
+                1 => &[(3, 1)],
+                2 => &[(3, 0)],
+                3 => &[(4, 5)],
+                _ => &[],
+            };
+            edges.iter().copied()
+        }
+    }
+
+    #[test]
+    fn searches() {
+        let paths = dijkstra(&Small, 0, |_| false);
+        assert_eq!(paths.dist(3), Some(2));
+        assert_eq!(paths.dist(4), Some(7));
+        let mut prev = paths.dag().prev(3).to_vec();
+        prev.sort();
+        assert_eq!(prev, vec![1, 2]);
+        assert_eq!(paths.end(), None);
+
+        let paths = bfs(&Small, 0, |n| n == 3);
+        assert_eq!((paths.end(), paths.ends()), (Some(2), &[3][..]));
+        assert_eq!(paths.dist(4), None);
+
+        let paths = astar(&Small, 0, |n| n == 4, |n| if n < 4 { 5 } else { 0 });
+        assert_eq!(paths.end(), Some(7));
+        assert_eq!(paths.dag().prev(4), &[3]);
+    }
+
+    #[test]
+    fn dag() {
+        let paths = dijkstra(&Small, 0, |_| false);
+        let dag = paths.dag();
+        assert_eq!(dag.count(&[4]), 2);
+        assert_eq!(dag.count(&[3, 1]), 3);
+        let mut all: Vec<Vec<u32>> = dag.paths(&[4]).collect();
+        all.sort();
+        assert_eq!(all, vec![vec![0, 1, 3, 4], vec![0, 2, 3, 4]]);
+        let mut states: Vec<u32> = dag.states(&[3]).into_iter().collect();
+        states.sort();
+        assert_eq!(states, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,155 @@
+use crate::parse::ParseError;
+use std::{any::Any, fmt::Display};
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Answer {
+    Num(i64),
+    Str(String),
+    // No answer for this input, with the reason why.
+    Unsolved(String),
+}
+
+// Numbers too big for an i64 are answers all the same, in decimal.
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(v: $t) -> Answer {
+                match i64::try_from(v) {
+                    Ok(n) => Answer::Num(n),
+                    Err(_) => Answer::Str(v.to_string()),
+                }
+            }
+        })*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Str(s.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Num(n) => write!(f, "{n}"),
+            Answer::Str(s) => write!(f, "{s}"),
+            Answer::Unsolved(why) => write!(f, "no answer: {why}"),
+        }
+    }
+}
+
+// Formats days can export their parsed input in, for looking at it with
+// other tools.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Export {
+    Dot,
+    Json,
+}
+
+pub trait Solution {
+    const DAY: u32;
+    type Input;
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    // Execution log for the days that can show how they got the answer.
+    fn trace(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    fn export(_input: &Self::Input, _format: Export) -> Option<String> {
+        None
+    }
+}
+
+// Type erased Solution, so all the days can live in a single table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub parse: fn(&str) -> ParseResult<Box<dyn Any>>,
+    pub part1: fn(&dyn Any) -> Answer,
+    pub part2: fn(&dyn Any) -> Answer,
+    pub trace: fn(&dyn Any) -> Option<String>,
+    pub export: fn(&dyn Any, Export) -> Option<String>,
+}
+
+impl Day {
+    pub const fn new<S>() -> Day
+    where
+        S: Solution,
+        S::Input: 'static,
+    {
+        Day {
+            parse: |s| match S::parse(s) {
+                Ok(input) => Ok(Box::new(input)),
+                Err(e) => Err(e.with_day(S::DAY)),
+            },
+            part1: |input| S::part1(input.downcast_ref().unwrap()),
+            part2: |input| S::part2(input.downcast_ref().unwrap()),
+            trace: |input| S::trace(input.downcast_ref().unwrap()),
+            export: |input, format| S::export(input.downcast_ref().unwrap(), format),
+        }
+    }
+
+    pub fn run(&self, part: usize, input: &str) -> ParseResult<Answer> {
+        let input = (self.parse)(input)?;
+        Ok(if part == 1 {
+            (self.part1)(input.as_ref())
+        } else {
+            (self.part2)(input.as_ref())
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::num;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 1;
+        type Input = Vec<i64>;
+
+        fn parse(input: &str) -> ParseResult<Self::Input> {
+            input.split_whitespace().map(|v| num(input, v)).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Answer {
+            input.iter().sum::<i64>().into()
+        }
+
+        fn part2(input: &Self::Input) -> Answer {
+            input.len().to_string().into()
+        }
+    }
+
+    #[test]
+    fn day() {
+        let day = Day::new::<Sum>();
+        assert_eq!(day.run(1, "1 2 3").unwrap(), Answer::Num(6));
+        assert_eq!(day.run(2, "1 2 3").unwrap(), Answer::Str("3".into()));
+        let err = day.run(1, "1 x").unwrap_err();
+        assert_eq!((err.day, err.line, err.column), (Some(1), 1, 3));
+
+        assert_eq!(Answer::from(u64::MAX >> 1), Answer::Num(i64::MAX));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Str("18446744073709551615".into())
+        );
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+    }
+}
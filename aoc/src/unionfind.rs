@@ -0,0 +1,92 @@
+// Disjoint sets of 0..n with union by size and path compression.
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> UnionFind {
+        UnionFind {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            count: n,
+        }
+    }
+
+    pub fn find(&mut self, mut n: usize) -> usize {
+        let mut root = n;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        while n != root {
+            n = std::mem::replace(&mut self.parent[n], root);
+        }
+        root
+    }
+
+    // False if they were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let mut a = self.find(a);
+        let mut b = self.find(b);
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            (a, b) = (b, a)
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // Size of the set containing `n`.
+    pub fn size(&mut self, n: usize) -> usize {
+        let root = self.find(n);
+        self.size[root]
+    }
+
+    // Number of disjoint sets.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    // All the sets, ordered by their smallest element.
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut idx = vec![usize::MAX; self.parent.len()];
+        let mut res: Vec<Vec<usize>> = Vec::new();
+        for n in 0..self.parent.len() {
+            let root = self.find(n);
+            if idx[root] == usize::MAX {
+                idx[root] = res.len();
+                res.push(Vec::new());
+            }
+            res[idx[root]].push(n);
+        }
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn sets() {
+        let mut uf = UnionFind::new(5);
+        assert!(uf.union(0, 1));
+        assert!(!uf.same(0, 2));
+        assert_eq!((uf.size(0), uf.count()), (2, 4));
+        assert_eq!(uf.components(), vec![vec![0, 1], vec![2], vec![3], vec![4]]);
+        assert!(uf.union(1, 2));
+        assert!(uf.union(3, 4));
+        assert!(!uf.union(0, 2));
+        assert_eq!((uf.size(2), uf.count()), (3, 2));
+        assert_eq!(uf.components(), vec![vec![0, 1, 2], vec![3, 4]]);
+    }
+}
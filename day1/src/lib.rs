@@ -0,0 +1,46 @@
+use aoc::parse;
+use aoc::solution::{Answer, ParseResult, Solution};
+use std::collections::HashMap;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = (Vec<i64>, Vec<i64>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut l1 = Vec::new();
+        let mut l2 = Vec::new();
+        for line in input.lines() {
+            let (a, b) = parse::split_once(input, line, " ")?;
+            l1.push(parse::num(input, a)?);
+            l2.push(parse::num(input, b.trim())?);
+        }
+        Ok((l1, l2))
+    }
+
+    fn part1((l1, l2): &Self::Input) -> Answer {
+        let mut l1 = l1.clone();
+        let mut l2 = l2.clone();
+        l1.sort();
+        l2.sort();
+        let mut dist: i64 = 0;
+        for (a, b) in l1.iter().zip(l2.iter()) {
+            dist += (a - b).abs();
+        }
+        dist.into()
+    }
+
+    fn part2((l1, l2): &Self::Input) -> Answer {
+        let mut freq: HashMap<i64, i64> = HashMap::new();
+        for v in l2 {
+            *freq.entry(*v).or_insert(0) += 1;
+        }
+
+        let mut dist: i64 = 0;
+        for v in l1 {
+            dist += v * (*freq.get(v).unwrap_or(&0));
+        }
+        dist.into()
+    }
+}
@@ -0,0 +1,80 @@
+use aoc::grid::{Grid, Pos};
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, ParseResult, Solution};
+
+const DIRS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+fn dfs(map: &Grid<u8>, visited: &mut Grid<bool>, pos: Pos) -> u32 {
+    visited[pos] = true;
+    let h = map[pos];
+    if h == 9 {
+        return 1;
+    }
+    let mut res = 0;
+    for next_pos in map.neighbours(DIRS.into_iter(), pos) {
+        if map[next_pos] == h + 1 && !visited[next_pos] {
+            res += dfs(map, visited, next_pos);
+        }
+    }
+    res
+}
+
+fn num_reachable(map: &Grid<u8>, start: Pos) -> u32 {
+    let mut visited = Grid::new(map.width(), map.height(), false);
+    dfs(map, &mut visited, start)
+}
+
+fn num_trails(map: &Grid<u8>, paths: &mut Grid<i32>, p: Pos) -> i32 {
+    let h = map[p];
+    if h == 9 {
+        return 1;
+    }
+    if paths[p] >= 0 {
+        return paths[p];
+    }
+    let mut res = 0;
+    for np in map.neighbours(DIRS.into_iter(), p) {
+        if map[np] == h + 1 {
+            res += num_trails(map, paths, np);
+        }
+    }
+    paths[p] = res;
+    res
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let map: Grid<u8> = input.parse()?;
+        if let Some(pos) = map.iter().find(|pos| !map[*pos].is_ascii_digit()) {
+            let line = input.trim().lines().nth(pos.y as usize).unwrap();
+            return Err(ParseError::new(input, &line[pos.x as usize..], "digit"));
+        }
+        Ok(map.map(|c| c - b'0'))
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        let mut res = 0;
+        for pos in map.iter() {
+            if map[pos] == 0 {
+                res += num_reachable(map, pos);
+            }
+        }
+        res.into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let mut res = 0;
+        let mut paths = Grid::new(map.width(), map.height(), -1);
+        for pos in map.iter() {
+            if map[pos] == 0 {
+                res += num_trails(map, &mut paths, pos);
+            }
+        }
+        res.into()
+    }
+}
@@ -1,5 +1,6 @@
+use aoc::parse;
+use aoc::solution::{Answer, ParseResult, Solution};
 use std::collections::HashMap;
-use std::io;
 
 fn num_digits(n: u64) -> u32 {
     let mut res = 1;
@@ -8,7 +9,7 @@ fn num_digits(n: u64) -> u32 {
         n /= 10;
         res += 1;
     }
-    return res;
+    res
 }
 
 fn compute(cache: &mut HashMap<(u64, u32), u64>, n: u64, d: u32) -> u64 {
@@ -20,7 +21,7 @@ fn compute(cache: &mut HashMap<(u64, u32), u64>, n: u64, d: u32) -> u64 {
     }
     let res = if n == 0 {
         compute(cache, 1, d - 1)
-    } else if num_digits(n) % 2 == 0 {
+    } else if num_digits(n).is_multiple_of(2) {
         let div = 10u64.pow(num_digits(n) / 2);
         compute(cache, n / div, d - 1) + compute(cache, n % div, d - 1)
     } else {
@@ -30,21 +31,31 @@ fn compute(cache: &mut HashMap<(u64, u32), u64>, n: u64, d: u32) -> u64 {
     res
 }
 
-fn main() {
-    let input: Vec<u64> = io::read_to_string(io::stdin())
-        .unwrap()
-        .trim()
-        .split_whitespace()
-        .map(|x| x.parse().unwrap())
-        .collect();
-    let depth = 75;
+fn blink(stones: &[u64], depth: u32) -> u64 {
     let mut cache = HashMap::new();
-    let res = input
-        .iter()
-        .map(|n| compute(&mut cache, *n, depth))
-        .reduce(|a, b| a + b)
-        .unwrap();
-    println!("{res}");
+    stones.iter().map(|n| compute(&mut cache, *n, depth)).sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .split_whitespace()
+            .map(|x| parse::num(input, x))
+            .collect()
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        blink(stones, 25).into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        blink(stones, 75).into()
+    }
 }
 
 #[cfg(test)]
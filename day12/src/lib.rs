@@ -0,0 +1,118 @@
+use aoc::grid::{self, Grid, Pos};
+use aoc::solution::{Answer, ParseResult, Solution};
+use enum_map::{Enum, EnumMap};
+
+#[derive(Debug, Enum, PartialEq, Clone, Copy)]
+enum Dir {
+    West,
+    East,
+    North,
+    South,
+}
+
+impl grid::Dir for Dir {
+    fn vector(&self) -> (i32, i32) {
+        match self {
+            Dir::West => (-1, 0),
+            Dir::East => (1, 0),
+            Dir::North => (0, -1),
+            Dir::South => (0, 1),
+        }
+    }
+}
+
+fn dfs(map: &Grid<u8>, visited: &mut Grid<bool>, p: Pos) -> (u32, u32) {
+    if visited[p] {
+        return (0, 0);
+    }
+    visited[p] = true;
+    let mut area = 1;
+    let mut perimeter = 0;
+    for d in [Dir::West, Dir::East, Dir::North, Dir::South] {
+        let np = p.adv(d);
+        if map.get(np) == Some(&map[p]) {
+            let r = dfs(map, visited, np);
+            perimeter += r.0;
+            area += r.1;
+        } else {
+            perimeter += 1
+        }
+    }
+    (perimeter, area)
+}
+
+fn dfs_sides(
+    map: &Grid<u8>,
+    visited: &mut Grid<bool>,
+    sides: &mut EnumMap<Dir, Vec<(i32, i32)>>,
+    p: Pos,
+) -> u32 {
+    visited[p] = true;
+    let mut area = 1;
+    for side in [Dir::West, Dir::East, Dir::North, Dir::South] {
+        let np = p.adv(side);
+        if map.get(np) == Some(&map[p]) {
+            if !visited[np] {
+                area += dfs_sides(map, visited, sides, np);
+            }
+        } else {
+            sides[side].push(if side == Dir::North || side == Dir::South {
+                (p.y, p.x)
+            } else {
+                (p.x, p.y)
+            });
+        }
+    }
+    area
+}
+
+fn count_sides(mut sides: EnumMap<Dir, Vec<(i32, i32)>>) -> u32 {
+    for s in sides.values_mut() {
+        s.sort();
+    }
+    let mut num_sides = 0;
+    for s in sides.values() {
+        let mut prev = (i32::MIN, i32::MIN);
+        for p in s {
+            if prev.0 != p.0 || prev.1 + 1 != p.1 {
+                num_sides += 1;
+            }
+            prev = *p;
+        }
+    }
+    num_sides
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        let mut visited = Grid::new(map.width(), map.height(), false);
+        let mut total_price = 0;
+        for pos in map.iter() {
+            let (perimeter, area) = dfs(map, &mut visited, pos);
+            total_price += perimeter * area;
+        }
+        total_price.into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        let mut visited = Grid::new(map.width(), map.height(), false);
+        let mut total_price = 0;
+        for pos in map.iter() {
+            if !visited[pos] {
+                let mut sides = EnumMap::from_fn(|_| Vec::new());
+                let area = dfs_sides(map, &mut visited, &mut sides, pos);
+                total_price += area * count_sides(sides);
+            }
+        }
+        total_price.into()
+    }
+}
@@ -0,0 +1,109 @@
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, ParseResult, Solution};
+use regex::Regex;
+use std::cmp::min;
+
+pub struct Machine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+const A_COST: i64 = 3;
+const B_COST: i64 = 1;
+
+fn get_cost_bruteforce(a: (i64, i64), b: (i64, i64), end: (i64, i64)) -> Option<i64> {
+    let mut res = None;
+
+    let max_a = min(min(end.0 / a.0, end.1 / a.1), 100);
+    for num_a in 0..=max_a {
+        let p = (end.0 - a.0 * num_a, end.1 - a.1 * num_a);
+        if p.0 % b.0 == 0 && p.1 % b.1 == 0 && p.0 / b.0 == p.1 / b.1 {
+            let num_b = p.0 / b.0;
+            let candidate = num_b * B_COST + num_a * A_COST;
+            if candidate < res.unwrap_or(i64::MAX) {
+                res = Some(candidate);
+            }
+        }
+    }
+
+    res
+}
+
+fn get_cost(a: (i64, i64), b: (i64, i64), r: (i64, i64)) -> Option<i64> {
+    // Verify that the vector are not overlaping, if they are, it would be
+    // an additional case to cover.
+    assert!(a.0 * b.1 != b.0 * a.1);
+
+    // Once we know they aren't overlapping, it's a solution over integers
+    // for a set of 2 linear equations:
+    //   a_num * a.0 + b_num * b.0 = r.0
+    //   a_num * a.1 + b_num * b.1 = r.1
+
+    let bn = r.0 * a.1 - r.1 * a.0;
+    let bd = b.0 * a.1 - b.1 * a.0;
+    if bn % bd != 0 {
+        return None;
+    }
+    let b_num = bn / bd;
+
+    let an = r.0 - b_num * b.0;
+    let ad = a.0;
+    if an % ad != 0 {
+        return None;
+    }
+    let a_num = an / ad;
+
+    Some(a_num * A_COST + b_num * B_COST)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let input_re = Regex::new(r"Button A: X\+(?<ax>\d+), Y\+(?<ay>\d+)\nButton B: X\+(?<bx>\d+), Y\+(?<by>\d+)\nPrize: X=(?<px>\d+), Y=(?<py>\d+)").unwrap();
+        input
+            .split("\n\n")
+            .map(|entry| {
+                let c = input_re.captures(entry).ok_or_else(|| {
+                    ParseError::new(
+                        input,
+                        entry,
+                        "Button A: X+<n>, Y+<n> ... Prize: X=<n>, Y=<n>",
+                    )
+                })?;
+                let num = |name| parse::num(input, &c[name]);
+                Ok(Machine {
+                    a: (num("ax")?, num("ay")?),
+                    b: (num("bx")?, num("by")?),
+                    prize: (num("px")?, num("py")?),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(machines: &Self::Input) -> Answer {
+        let total_price: i64 = machines
+            .iter()
+            .filter_map(|m| get_cost_bruteforce(m.a, m.b, m.prize))
+            .sum();
+        total_price.into()
+    }
+
+    fn part2(machines: &Self::Input) -> Answer {
+        let total_price: i64 = machines
+            .iter()
+            .filter_map(|m| {
+                get_cost(
+                    m.a,
+                    m.b,
+                    (m.prize.0 + 10000000000000, m.prize.1 + 10000000000000),
+                )
+            })
+            .sum();
+        total_price.into()
+    }
+}
@@ -0,0 +1,96 @@
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, ParseResult, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+
+const SIZE: (i64, i64) = (101, 103);
+
+pub struct Robot {
+    p: (i64, i64),
+    v: (i64, i64),
+}
+
+fn simulate_steps(r: &Robot, steps: i64, (w, h): (i64, i64)) -> Robot {
+    Robot {
+        p: (
+            (r.p.0 + r.v.0 * steps % w + w) % w,
+            (r.p.1 + r.v.1 * steps % h + h) % h,
+        ),
+        v: r.v,
+    }
+}
+
+fn quadrant(r: &Robot, (w, h): (i64, i64)) -> Option<usize> {
+    if r.p.0 == w / 2 || r.p.1 == h / 2 {
+        return None;
+    }
+    let xq = if r.p.0 < w / 2 { 0 } else { 1 };
+    let yq = if r.p.1 < h / 2 { 0 } else { 2 };
+    Some(xq + yq)
+}
+
+// Product of the robots in each quadrant after 100 seconds.
+fn safety_factor(robots: &[Robot], size: (i64, i64)) -> u64 {
+    let mut q: [u64; 4] = [0, 0, 0, 0];
+    for r in robots.iter() {
+        let new_r = simulate_steps(r, 100, size);
+        if let Some(qn) = quadrant(&new_r, size) {
+            q[qn] += 1;
+        }
+    }
+    q.into_iter().product()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input = Vec<Robot>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let re = Regex::new(r"p=(?<px>\d+),(?<py>\d+) v=(?<vx>\-?\d+),(?<vy>\-?\d+)").unwrap();
+        input
+            .lines()
+            .map(|line| {
+                let c = re
+                    .captures(line)
+                    .ok_or_else(|| ParseError::new(input, line, "p=<x>,<y> v=<x>,<y>"))?;
+                let num = |name| parse::num(input, &c[name]);
+                Ok(Robot {
+                    p: (num("px")?, num("py")?),
+                    v: (num("vx")?, num("vy")?),
+                })
+            })
+            .collect()
+    }
+
+    fn part1(robots: &Self::Input) -> Answer {
+        safety_factor(robots, SIZE).into()
+    }
+
+    // Originally found by looking at the pictures with the day14-viewer, but
+    // it turns out the tree is drawn exactly when no two robots overlap.
+    fn part2(robots: &Self::Input) -> Answer {
+        let step = (0..SIZE.0 * SIZE.1)
+            .find(|step| {
+                let mut seen = HashSet::new();
+                robots
+                    .iter()
+                    .all(|r| seen.insert(simulate_steps(r, *step, SIZE).p))
+            })
+            .unwrap();
+        step.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        // The example is in a much smaller room.
+        let robots = Day14::parse(include_str!("../../examples/day14.txt")).unwrap();
+        assert_eq!(safety_factor(&robots, (11, 7)), 12);
+    }
+}
@@ -0,0 +1,238 @@
+use aoc::grid::{self, Grid, Pos};
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, ParseResult, Solution};
+
+#[derive(Clone, Copy, Debug)]
+pub enum Dir {
+    East,
+    West,
+    North,
+    South,
+}
+
+impl Dir {
+    fn from_char(c: char) -> Option<Dir> {
+        match c {
+            '<' => Some(Dir::West),
+            '>' => Some(Dir::East),
+            '^' => Some(Dir::North),
+            'v' => Some(Dir::South),
+            _ => None,
+        }
+    }
+
+    fn horizontal(&self) -> bool {
+        match self {
+            Dir::East | Dir::West => true,
+            Dir::North | Dir::South => false,
+        }
+    }
+}
+
+impl grid::Dir for Dir {
+    fn vector(&self) -> (i32, i32) {
+        match self {
+            Dir::East => (1, 0),
+            Dir::West => (-1, 0),
+            Dir::North => (0, -1),
+            Dir::South => (0, 1),
+        }
+    }
+}
+
+// We take adventage of the fact that grid in this task is very nice and has
+// border so we never try to move outside of it 🎉.
+fn try_move(grid: &mut Grid<u8>, pos: Pos, dir: Dir) -> bool {
+    if grid[pos] == b'.' {
+        return true;
+    } else if grid[pos] == b'#' {
+        return false;
+    }
+
+    let next_pos = pos.adv(dir);
+    if try_move(grid, next_pos, dir) {
+        grid[next_pos] = grid[pos];
+        grid[pos] = b'.';
+        true
+    } else {
+        false
+    }
+}
+
+fn compute_result(grid: &Grid<u8>) -> i32 {
+    grid.iter()
+        .filter(|pos| grid[*pos] == b'O')
+        .map(|pos| pos.y * 100 + pos.x)
+        .sum()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum GridBox {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum GridElem {
+    Empty,
+    Wall,
+    Robot,
+    Box(GridBox),
+}
+
+fn other_box_part(box_part: GridBox, pos: Pos) -> Pos {
+    match box_part {
+        GridBox::Left => pos.adv(Dir::East),
+        GridBox::Right => pos.adv(Dir::West),
+    }
+}
+
+fn can_move(grid: &Grid<GridElem>, pos: Pos, dir: Dir) -> bool {
+    match grid[pos] {
+        GridElem::Empty => true,
+        GridElem::Wall => false,
+        GridElem::Box(b) => {
+            if dir.horizontal() {
+                can_move(grid, pos.adv(dir).adv(dir), dir)
+            } else {
+                let other = other_box_part(b, pos);
+                can_move(grid, pos.adv(dir), dir) && can_move(grid, other.adv(dir), dir)
+            }
+        }
+        GridElem::Robot => can_move(grid, pos.adv(dir), dir),
+    }
+}
+
+fn do_move_single_piece(grid: &mut Grid<GridElem>, pos: Pos, dir: Dir) {
+    let next_pos = pos.adv(dir);
+    do_move(grid, next_pos, dir);
+    grid[next_pos] = grid[pos];
+    grid[pos] = GridElem::Empty;
+}
+
+fn do_move(grid: &mut Grid<GridElem>, pos: Pos, dir: Dir) {
+    match grid[pos] {
+        GridElem::Empty => (),
+        GridElem::Robot => do_move_single_piece(grid, pos, dir),
+        GridElem::Box(b) => {
+            if dir.horizontal() {
+                do_move_single_piece(grid, pos, dir);
+            } else {
+                let other = other_box_part(b, pos);
+                do_move_single_piece(grid, pos, dir);
+                do_move_single_piece(grid, other, dir);
+            }
+        }
+        GridElem::Wall => panic!("impossible to move wall"),
+    }
+}
+
+#[allow(dead_code)]
+fn print_grid(grid: &Grid<GridElem>) {
+    let out = grid.map(|c| match c {
+        GridElem::Empty => b'.',
+        GridElem::Wall => b'#',
+        GridElem::Robot => b'@',
+        GridElem::Box(GridBox::Left) => b'[',
+        GridElem::Box(GridBox::Right) => b']',
+    });
+    println!("{}", out);
+}
+
+fn compute_wide_result(grid: &Grid<GridElem>) -> i32 {
+    grid.iter()
+        .filter(|pos| grid[*pos] == GridElem::Box(GridBox::Left))
+        .map(|pos| pos.y * 100 + pos.x)
+        .sum()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = (Grid<u8>, Pos, Vec<Dir>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let (grid_str, steps_str) = parse::split_once(input, input, "\n\n")?;
+        let grid: Grid<u8> = grid_str
+            .parse()
+            .map_err(|e: ParseError| e.rebase(input, grid_str))?;
+        if let Some(i) = grid_str.find(|c| !"#.O@\n".contains(c)) {
+            return Err(ParseError::new(
+                input,
+                &grid_str[i..],
+                "tile '#', '.', 'O' or '@'",
+            ));
+        }
+        let robot = grid
+            .find(&b'@')
+            .ok_or_else(|| ParseError::new(input, &grid_str[grid_str.len()..], "robot tile '@'"))?;
+        let steps = steps_str
+            .char_indices()
+            .filter(|(_, c)| !c.is_whitespace())
+            .map(|(i, c)| {
+                Dir::from_char(c).ok_or_else(|| ParseError::new(input, &steps_str[i..], "move"))
+            })
+            .collect::<ParseResult<_>>()?;
+        Ok((grid, robot, steps))
+    }
+
+    fn part1((grid, robot, steps): &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+
+        let mut pos = *robot;
+        for &s in steps {
+            if try_move(&mut grid, pos, s) {
+                pos = pos.adv(s)
+            }
+        }
+
+        compute_result(&grid).into()
+    }
+
+    fn part2((small_grid, robot, steps): &Self::Input) -> Answer {
+        let mut grid = Grid::new_with(small_grid.width() * 2, small_grid.height(), |x, y| {
+            let [left, right] = match small_grid[Pos::new((x / 2) as i32, y as i32)] {
+                b'#' => [GridElem::Wall, GridElem::Wall],
+                b'.' => [GridElem::Empty, GridElem::Empty],
+                b'O' => [GridElem::Box(GridBox::Left), GridElem::Box(GridBox::Right)],
+                b'@' => [GridElem::Robot, GridElem::Empty],
+                _ => unreachable!("checked by parse"),
+            };
+            if x % 2 == 0 {
+                left
+            } else {
+                right
+            }
+        });
+
+        let mut pos = Pos::new(robot.x * 2, robot.y);
+        //print_grid(&grid);
+        for &s in steps {
+            //dbg!(s);
+            if can_move(&grid, pos, s) {
+                do_move(&mut grid, pos, s);
+                pos = pos.adv(s);
+                //print_grid(&grid);
+            } else {
+                //println!("can't move");
+            }
+        }
+
+        compute_wide_result(&grid).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tiles() {
+        let err = Day15::parse("###\n#.#\n###\n\n<>\n").err().unwrap();
+        assert_eq!(err.expected, "robot tile '@'");
+        let err = Day15::parse("####\n#@x#\n####\n\n<>\n").err().unwrap();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.expected, "tile '#', '.', 'O' or '@'");
+    }
+}
@@ -0,0 +1,114 @@
+use aoc::grid::{self, Grid, Pos};
+use aoc::parse::ParseError;
+use aoc::search;
+use aoc::solution::{Answer, ParseResult, Solution};
+use std::collections::HashSet;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Dir {
+    East,
+    West,
+    North,
+    South,
+}
+
+impl grid::Dir for Dir {
+    fn vector(&self) -> (i32, i32) {
+        match self {
+            Dir::East => (1, 0),
+            Dir::West => (-1, 0),
+            Dir::North => (0, -1),
+            Dir::South => (0, 1),
+        }
+    }
+}
+
+const DIRS: [Dir; 4] = [Dir::East, Dir::West, Dir::South, Dir::North];
+
+// Search states are the position with the direction the reindeer faces.
+struct Maze<'a>(&'a Grid<u8>);
+
+impl search::Graph for Maze<'_> {
+    type Node = (Pos, Dir);
+    type Cost = u32;
+
+    fn neighbours(&self, (pos, dir): (Pos, Dir)) -> impl Iterator<Item = ((Pos, Dir), u32)> {
+        DIRS.into_iter().filter_map(move |next_dir| {
+            let edge_cost = if next_dir == dir { 1 } else { 1001 };
+            let next_pos = pos.adv(next_dir);
+            (self.0[next_pos] != b'#').then_some(((next_pos, next_dir), edge_cost))
+        })
+    }
+}
+
+fn find_path(grid: &Grid<u8>, start: Pos) -> Option<u32> {
+    let end = grid.find(&b'E')?;
+    // Every step costs at least 1, so the distance never overestimates.
+    search::astar(
+        &Maze(grid),
+        (start, Dir::East),
+        |(pos, _)| pos == end,
+        |(pos, _)| pos.manhattan(end) as u32,
+    )
+    .end()
+}
+
+fn good_seats(grid: &Grid<u8>, start: Pos) -> Option<usize> {
+    let paths = search::dijkstra(&Maze(grid), (start, Dir::East), |(pos, _)| {
+        grid[pos] == b'E'
+    });
+    paths.end()?;
+
+    let visited = paths.dag().states(paths.ends());
+
+    let unique_pos: HashSet<Pos> = HashSet::from_iter(visited.iter().map(|(pos, _dir)| *pos));
+    Some(unique_pos.len())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = (Grid<u8>, Pos);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let grid: Grid<u8> = input.parse()?;
+        let find = |c: u8, what: &str| {
+            grid.find(&c)
+                .ok_or_else(|| ParseError::new(input, &input[input.len()..], what))
+        };
+        let start = find(b'S', "start tile 'S'")?;
+        find(b'E', "end tile 'E'")?;
+        Ok((grid, start))
+    }
+
+    fn part1((grid, start): &Self::Input) -> Answer {
+        match find_path(grid, *start) {
+            Some(score) => score.into(),
+            None => Answer::Unsolved("no path to the end tile".to_string()),
+        }
+    }
+
+    fn part2((grid, start): &Self::Input) -> Answer {
+        match good_seats(grid, *start) {
+            Some(seats) => seats.into(),
+            None => Answer::Unsolved("no path to the end tile".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tiles() {
+        let err = Day16::parse("#####\n#S..#\n#####\n").err().unwrap();
+        assert_eq!(err.expected, "end tile 'E'");
+
+        let walled_off = Day16::parse("#######\n#S.#.E#\n#######\n").unwrap();
+        let unsolved = Answer::Unsolved("no path to the end tile".to_string());
+        assert_eq!(Day16::part1(&walled_off), unsolved);
+        assert_eq!(Day16::part2(&walled_off), unsolved);
+    }
+}
@@ -0,0 +1,78 @@
+// Runs per second of the original interpreter loop and the pre-decoded one
+// on a typical puzzle program, both collecting the same output:
+// cargo run --release -p aoc2024day17 --example throughput
+use aoc2024day17::fast::Compiled;
+use aoc2024day17::{decode, Combo, Op, Reg};
+use enum_map::{enum_map, EnumMap};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const RUNS: u64 = 1_000_000;
+
+fn val(c: &Combo, reg: &EnumMap<Reg, u64>) -> u64 {
+    match c {
+        Combo::Val(v) => *v,
+        Combo::Reg(r) => reg[*r],
+    }
+}
+
+// The loop the solution started with, before the debugger and the blocks.
+fn baseline(mut reg: EnumMap<Reg, u64>, prog: &[Op]) -> Vec<u64> {
+    let mut out = Vec::new();
+
+    let mut ip = 0;
+    while let Some(op) = prog.get(ip) {
+        match op {
+            Op::Div(c, r) => {
+                reg[*r] = reg[Reg::A] >> val(c, &reg);
+            }
+            Op::Bxl(l) => {
+                reg[Reg::B] ^= l;
+            }
+            Op::Bst(c) => {
+                reg[Reg::B] = val(c, &reg) % 8;
+            }
+            Op::Jnz(p) => {
+                if reg[Reg::A] != 0 {
+                    ip = *p;
+                    continue;
+                }
+            }
+            Op::Bxc(_) => {
+                reg[Reg::B] ^= reg[Reg::C];
+            }
+            Op::Out(c) => {
+                out.push(val(c, &reg) % 8);
+            }
+        };
+        ip += 1;
+    }
+    out
+}
+
+fn measure(name: &str, mut f: impl FnMut(u64) -> Vec<u64>) {
+    let start = Instant::now();
+    let mut sum = 0;
+    for a in 0..RUNS {
+        sum += f(black_box(a << 20)).iter().sum::<u64>();
+    }
+    black_box(sum);
+    let elapsed: Duration = start.elapsed();
+    println!(
+        "{name:>10}: {:>12.0} runs/s",
+        RUNS as f64 / elapsed.as_secs_f64()
+    );
+}
+
+fn main() {
+    let code = [2, 4, 1, 5, 7, 5, 1, 6, 0, 3, 4, 0, 5, 5, 3, 0];
+    let prog = decode(&code).unwrap();
+    let compiled = Compiled::new(&prog);
+    let regs = |a| enum_map! { Reg::A => a, Reg::B => 0, Reg::C => 0 };
+    for a in [0, 1, 2024, 1 << 40, u64::MAX] {
+        assert_eq!(baseline(regs(a), &prog), compiled.output(regs(a)));
+    }
+
+    measure("baseline", |a| baseline(regs(a), &prog));
+    measure("compiled", |a| compiled.output(regs(a)));
+}
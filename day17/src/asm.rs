@@ -0,0 +1,142 @@
+use crate::{Combo, Op, Reg};
+use aoc::parse::{self, ParseError};
+use aoc::solution::ParseResult;
+use std::fmt::Display;
+
+impl Display for Combo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Combo::Val(v) => write!(f, "{v}"),
+            Combo::Reg(r) => write!(f, "{r:?}"),
+        }
+    }
+}
+
+// Mnemonics from the puzzle description, the operand of bxc is ignored by
+// the machine, so it's only shown when it isn't 0.
+impl Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Div(c, Reg::A) => write!(f, "adv {c}"),
+            Op::Div(c, Reg::B) => write!(f, "bdv {c}"),
+            Op::Div(c, Reg::C) => write!(f, "cdv {c}"),
+            Op::Bxl(l) => write!(f, "bxl {l}"),
+            Op::Bst(c) => write!(f, "bst {c}"),
+            Op::Jnz(p) => write!(f, "jnz {p}"),
+            Op::Bxc(0) => write!(f, "bxc"),
+            Op::Bxc(l) => write!(f, "bxc {l}"),
+            Op::Out(c) => write!(f, "out {c}"),
+        }
+    }
+}
+
+impl Combo {
+    fn encode(&self) -> u64 {
+        match self {
+            Combo::Val(v) => *v,
+            Combo::Reg(Reg::A) => 4,
+            Combo::Reg(Reg::B) => 5,
+            Combo::Reg(Reg::C) => 6,
+        }
+    }
+}
+
+impl Op {
+    // Opcode and operand, the reverse of decode().
+    pub fn encode(&self) -> [u64; 2] {
+        match self {
+            Op::Div(c, Reg::A) => [0, c.encode()],
+            Op::Bxl(l) => [1, *l],
+            Op::Bst(c) => [2, c.encode()],
+            Op::Jnz(p) => [3, *p as u64],
+            Op::Bxc(l) => [4, *l],
+            Op::Out(c) => [5, c.encode()],
+            Op::Div(c, Reg::B) => [6, c.encode()],
+            Op::Div(c, Reg::C) => [7, c.encode()],
+        }
+    }
+}
+
+pub fn disassemble(prog: &[Op]) -> String {
+    prog.iter().map(|op| format!("{op}\n")).collect()
+}
+
+// The comma separated form used by the puzzle input.
+pub fn code_of(prog: &[Op]) -> String {
+    let code: Vec<String> = prog
+        .iter()
+        .flat_map(|op| op.encode())
+        .map(|v| v.to_string())
+        .collect();
+    code.join(",")
+}
+
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+fn literal(input: &str, s: &str, max: u64) -> ParseResult<u64> {
+    let v = parse::num(input, s)?;
+    if v > max {
+        return Err(ParseError::new(input, s, format!("0-{max}")));
+    }
+    Ok(v)
+}
+
+// One instruction per line as printed by disassemble(), ';' starts a comment.
+pub fn assemble(input: &str) -> ParseResult<Vec<Op>> {
+    input
+        .lines()
+        .map(|l| l.split(';').next().unwrap().trim())
+        .filter(|l| !l.is_empty())
+        .map(|line| {
+            let mut words = line.split_whitespace();
+            let mnemonic = words.next().unwrap();
+            let opcode = MNEMONICS
+                .iter()
+                .position(|m| *m == mnemonic)
+                .ok_or_else(|| ParseError::new(input, mnemonic, "mnemonic"))?;
+            let combo = matches!(opcode, 0 | 2 | 5 | 6 | 7);
+            let operand = match words.next() {
+                None if opcode == 4 => 0,
+                None => return Err(ParseError::new(input, &line[line.len()..], "operand")),
+                Some("A") if combo => 4,
+                Some("B") if combo => 5,
+                Some("C") if combo => 6,
+                Some(s) if combo => literal(input, s, 3)?,
+                Some(s) => literal(input, s, 7)?,
+            };
+            if let Some(extra) = words.next() {
+                return Err(ParseError::new(input, extra, "end of line"));
+            }
+            Ok(Op::decode(opcode as u32, operand as u32).unwrap())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::decode;
+
+    #[test]
+    fn round_trip() {
+        let code = [2, 4, 1, 1, 7, 5, 4, 3, 0, 3, 1, 6, 5, 5, 3, 0];
+        let prog = decode(&code).unwrap();
+        let text = disassemble(&prog);
+        assert_eq!(
+            text,
+            "bst A\nbxl 1\ncdv B\nbxc 3\nadv 3\nbxl 6\nout B\njnz 0\n"
+        );
+        let asm = assemble(&text).unwrap();
+        assert_eq!(asm, prog);
+        assert_eq!(code_of(&asm), "2,4,1,1,7,5,4,3,0,3,1,6,5,5,3,0");
+
+        let err = assemble("bst A ; comment\n\nadv 7\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.expected, "0-3");
+        let err = assemble("out\n").unwrap_err();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (1, 4, "operand")
+        );
+    }
+}
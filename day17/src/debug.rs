@@ -0,0 +1,175 @@
+use crate::{shr, Op, Reg};
+use enum_map::EnumMap;
+use std::fmt::Write;
+
+// State of a running program, every step() executes one instruction.
+#[derive(Clone, Debug)]
+pub struct Machine<'a> {
+    prog: &'a [Op],
+    pub ip: usize,
+    pub regs: EnumMap<Reg, u64>,
+    pub out: Vec<u64>,
+    pub steps: usize,
+    // Stopped by a breakpoint at ip, the next run starts past it.
+    at_breakpoint: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Stop {
+    Halted,
+    Breakpoint(usize),
+    StepLimit,
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct Debugger {
+    pub breakpoints: Vec<usize>,
+    pub step_limit: Option<usize>,
+}
+
+impl<'a> Machine<'a> {
+    pub fn new(prog: &'a [Op], regs: EnumMap<Reg, u64>) -> Machine<'a> {
+        Machine {
+            prog,
+            ip: 0,
+            regs,
+            out: Vec::new(),
+            steps: 0,
+            at_breakpoint: false,
+        }
+    }
+
+    // Instruction at ip, None once the program halted.
+    pub fn op(&self) -> Option<Op> {
+        self.prog.get(self.ip).copied()
+    }
+
+    // False if the program already halted.
+    pub fn step(&mut self) -> bool {
+        let Some(op) = self.op() else {
+            return false;
+        };
+        self.at_breakpoint = false;
+        let reg = &mut self.regs;
+        match op {
+            Op::Div(c, r) => {
+                reg[r] = shr(reg[Reg::A], c.val(reg));
+            }
+            Op::Bxl(l) => {
+                reg[Reg::B] ^= l;
+            }
+            Op::Bst(c) => {
+                reg[Reg::B] = c.val(reg) % 8;
+            }
+            Op::Jnz(p) => {
+                if reg[Reg::A] != 0 {
+                    self.ip = p;
+                    self.steps += 1;
+                    return true;
+                }
+            }
+            Op::Bxc(_) => {
+                reg[Reg::B] ^= reg[Reg::C];
+            }
+            Op::Out(c) => {
+                self.out.push(c.val(reg) % 8);
+            }
+        };
+        self.ip += 1;
+        self.steps += 1;
+        true
+    }
+
+    // Runs until the program halts or the debugger stops it, `trace` sees the
+    // machine before every instruction. A breakpoint stops before executing
+    // its instruction, running again continues past it.
+    pub fn run<F>(&mut self, debugger: &Debugger, mut trace: F) -> Stop
+    where
+        F: FnMut(&Machine),
+    {
+        let start = self.steps;
+        while self.op().is_some() {
+            if !self.at_breakpoint && debugger.breakpoints.contains(&self.ip) {
+                self.at_breakpoint = true;
+                return Stop::Breakpoint(self.ip);
+            }
+            if debugger.step_limit.is_some_and(|l| self.steps - start >= l) {
+                return Stop::StepLimit;
+            }
+            trace(self);
+            self.step();
+        }
+        Stop::Halted
+    }
+}
+
+// One line per executed instruction with the registers before it.
+pub fn trace_line(m: &Machine) -> String {
+    let mut line = String::new();
+    write!(
+        line,
+        "{:5} ip {:2}  {:<16}",
+        m.steps,
+        m.ip,
+        m.op().unwrap().to_string()
+    )
+    .unwrap();
+    for (r, v) in m.regs {
+        write!(line, " {:?}={}", r, v).unwrap();
+    }
+    line
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::decode;
+    use enum_map::enum_map;
+
+    #[test]
+    fn breakpoints() {
+        // adv 1, out A, jnz 0
+        let prog = decode(&[0, 1, 5, 4, 3, 0]).unwrap();
+        let regs = enum_map! { Reg::A => 4, Reg::B => 0, Reg::C => 0 };
+        let mut m = Machine::new(&prog, regs);
+        let debugger = Debugger {
+            breakpoints: vec![1],
+            step_limit: None,
+        };
+        let mut ips = Vec::new();
+        assert_eq!(m.run(&debugger, |m| ips.push(m.ip)), Stop::Breakpoint(1));
+        assert_eq!((ips, m.regs[Reg::A]), (vec![0], 2));
+        assert_eq!(m.run(&debugger, |_| {}), Stop::Breakpoint(1));
+        assert_eq!(m.run(&Debugger::default(), |_| {}), Stop::Halted);
+        assert_eq!(m.out, vec![2, 1, 0]);
+
+        // Stops before the first instruction too.
+        let mut m = Machine::new(&prog, regs);
+        let debugger = Debugger {
+            breakpoints: vec![0],
+            step_limit: None,
+        };
+        assert_eq!(m.run(&debugger, |_| {}), Stop::Breakpoint(0));
+        assert_eq!(m.steps, 0);
+        assert_eq!(m.run(&debugger, |_| {}), Stop::Breakpoint(0));
+        assert_eq!((m.steps, m.out.as_slice()), (3, [2].as_slice()));
+
+        // jnz 0 forever
+        let prog = decode(&[3, 0]).unwrap();
+        let mut m = Machine::new(&prog, regs);
+        let debugger = Debugger {
+            breakpoints: vec![],
+            step_limit: Some(100),
+        };
+        assert_eq!(m.run(&debugger, |_| {}), Stop::StepLimit);
+        assert_eq!(m.steps, 100);
+
+        // adv B, out A with B past the width of A
+        let prog = decode(&[0, 5, 5, 4]).unwrap();
+        for b in [64, 1 << 32] {
+            let mut m = Machine::new(&prog, enum_map! { Reg::A => 7, Reg::B => b, Reg::C => 0 });
+            assert_eq!(m.run(&Debugger::default(), |_| {}), Stop::Halted);
+            assert_eq!(m.out, vec![0]);
+        }
+    }
+}
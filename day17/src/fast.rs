@@ -0,0 +1,157 @@
+use crate::{shr, Combo, Op, Reg};
+use enum_map::EnumMap;
+
+// Instructions with the combo operands already resolved, registers are
+// indexes into a plain array.
+#[derive(Clone, Copy, Debug)]
+enum Inst {
+    ShrVal(usize, u32),
+    ShrReg(usize, usize),
+    Xor(u64),
+    Set(u64),
+    Mod(usize),
+    Bxc,
+    OutVal(u64),
+    OutReg(usize),
+}
+
+// Straight line code up to the next jump or jump target.
+#[derive(Clone, Debug)]
+struct Block {
+    insts: Vec<Inst>,
+    // Block to continue with while A isn't 0, None for no jnz at the end.
+    jump: Option<usize>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Compiled {
+    blocks: Vec<Block>,
+}
+
+impl Compiled {
+    pub fn new(prog: &[Op]) -> Compiled {
+        // Jumps outside of the program halt, they all go to the extra block
+        // past the last one.
+        let mut leader = vec![false; prog.len() + 1];
+        leader[0] = true;
+        leader[prog.len()] = true;
+        for (ip, op) in prog.iter().enumerate() {
+            if let Op::Jnz(p) = op {
+                leader[(*p).min(prog.len())] = true;
+                leader[ip + 1] = true;
+            }
+        }
+        let block_of: Vec<usize> = leader
+            .iter()
+            .scan(0, |n, l| {
+                *n += *l as usize;
+                Some(*n - 1)
+            })
+            .collect();
+
+        let mut blocks = vec![
+            Block {
+                insts: Vec::new(),
+                jump: None,
+            };
+            block_of[prog.len()]
+        ];
+        let r = |r: Reg| r as usize;
+        for (ip, op) in prog.iter().enumerate() {
+            let block = &mut blocks[block_of[ip]];
+            block.insts.push(match *op {
+                Op::Div(Combo::Val(v), dst) => Inst::ShrVal(r(dst), v as u32),
+                Op::Div(Combo::Reg(src), dst) => Inst::ShrReg(r(dst), r(src)),
+                Op::Bxl(l) => Inst::Xor(l),
+                Op::Bst(Combo::Val(v)) => Inst::Set(v),
+                Op::Bst(Combo::Reg(src)) => Inst::Mod(r(src)),
+                Op::Jnz(p) => {
+                    block.jump = Some(block_of[p.min(prog.len())]);
+                    continue;
+                }
+                Op::Bxc(_) => Inst::Bxc,
+                Op::Out(Combo::Val(v)) => Inst::OutVal(v),
+                Op::Out(Combo::Reg(src)) => Inst::OutReg(r(src)),
+            });
+        }
+        Compiled { blocks }
+    }
+
+    // Calls `out` for every output value, stops early once it returns false.
+    pub fn run<F>(&self, regs: EnumMap<Reg, u64>, mut out: F)
+    where
+        F: FnMut(u64) -> bool,
+    {
+        let mut reg = [regs[Reg::A], regs[Reg::B], regs[Reg::C]];
+        let mut block = 0;
+        while let Some(b) = self.blocks.get(block) {
+            for inst in b.insts.iter() {
+                match *inst {
+                    Inst::ShrVal(dst, v) => reg[dst] = reg[0] >> v,
+                    Inst::ShrReg(dst, src) => reg[dst] = shr(reg[0], reg[src]),
+                    Inst::Xor(l) => reg[1] ^= l,
+                    Inst::Set(v) => reg[1] = v,
+                    Inst::Mod(src) => reg[1] = reg[src] % 8,
+                    Inst::Bxc => reg[1] ^= reg[2],
+                    Inst::OutVal(v) => {
+                        if !out(v) {
+                            return;
+                        }
+                    }
+                    Inst::OutReg(src) => {
+                        if !out(reg[src] % 8) {
+                            return;
+                        }
+                    }
+                }
+            }
+            block = match b.jump {
+                Some(target) if reg[0] != 0 => target,
+                _ => block + 1,
+            };
+        }
+    }
+
+    pub fn output(&self, regs: EnumMap<Reg, u64>) -> Vec<u64> {
+        let mut res = Vec::new();
+        self.run(regs, |v| {
+            res.push(v);
+            true
+        });
+        res
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{decode, simulate};
+    use enum_map::enum_map;
+
+    #[test]
+    fn same_as_simulate() {
+        for code in [
+            vec![0, 1, 5, 4, 3, 0],
+            vec![2, 4, 1, 5, 7, 5, 1, 6, 0, 3, 4, 0, 5, 5, 3, 0],
+            // Jumps into the middle, past the end and two loops.
+            vec![5, 4, 3, 3, 0, 2, 0, 1, 5, 5, 3, 2, 3, 7],
+            vec![6, 1, 5, 5, 0, 1, 3, 0, 7, 3, 5, 6],
+            // Shifts by B and C before they are set.
+            vec![0, 6, 7, 5, 5, 4, 5, 6],
+        ] {
+            let prog = decode(&code).unwrap();
+            let compiled = Compiled::new(&prog);
+            // B and C at least 64 shift everything out, 1 << 32 doesn't fit a u32.
+            for (b, c) in [(3, 5), (64, 1 << 32)] {
+                for a in [0, 1, 7, 729, 117440, 1 << 40] {
+                    let regs = enum_map! { Reg::A => a, Reg::B => b, Reg::C => c };
+                    assert_eq!(
+                        compiled.output(regs),
+                        simulate(regs, &prog),
+                        "{code:?} {a} {b} {c}"
+                    );
+                }
+            }
+        }
+    }
+}
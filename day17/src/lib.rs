@@ -0,0 +1,177 @@
+pub mod asm;
+pub mod debug;
+pub mod fast;
+pub mod quine;
+pub mod symbolic;
+
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, ParseResult, Solution};
+use debug::{Debugger, Machine, Stop};
+use enum_map::{enum_map, Enum, EnumMap};
+use fast::Compiled;
+use itertools::{self, Itertools};
+use regex::Regex;
+
+#[derive(Enum, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Reg {
+    A,
+    B,
+    C,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Combo {
+    Val(u64),
+    Reg(Reg),
+}
+
+impl Combo {
+    fn decode(operand: u32) -> Option<Self> {
+        match operand {
+            0..=3 => Some(Self::Val(operand as u64)),
+            4 => Some(Self::Reg(Reg::A)),
+            5 => Some(Self::Reg(Reg::B)),
+            6 => Some(Self::Reg(Reg::C)),
+            _ => None,
+        }
+    }
+
+    fn val(&self, reg: &EnumMap<Reg, u64>) -> u64 {
+        match self {
+            Self::Val(v) => *v,
+            Self::Reg(r) => reg[*r],
+        }
+    }
+}
+
+// a >> s for the adv, bdv and cdv shifts, by 64 or more leaves nothing.
+fn shr(a: u64, s: u64) -> u64 {
+    u32::try_from(s)
+        .ok()
+        .and_then(|s| a.checked_shr(s))
+        .unwrap_or(0)
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Op {
+    Div(Combo, Reg),
+    Bxl(u64),
+    Bst(Combo),
+    Jnz(usize),
+    // The operand is ignored, but kept so the program encodes back the same.
+    Bxc(u64),
+    Out(Combo),
+}
+
+impl Op {
+    fn decode(opcode: u32, operand: u32) -> Option<Self> {
+        Some(match opcode {
+            0 => Self::Div(Combo::decode(operand)?, Reg::A),
+            1 => Self::Bxl(operand as u64),
+            2 => Self::Bst(Combo::decode(operand)?),
+            3 => Self::Jnz(operand as usize),
+            4 => Self::Bxc(operand as u64),
+            5 => Self::Out(Combo::decode(operand)?),
+            6 => Self::Div(Combo::decode(operand)?, Reg::B),
+            7 => Self::Div(Combo::decode(operand)?, Reg::C),
+            _ => return None,
+        })
+    }
+}
+
+// Reference implementation, see fast::Compiled for the quick one.
+pub fn simulate(reg: EnumMap<Reg, u64>, prog: &[Op]) -> Vec<u64> {
+    let mut machine = Machine::new(prog, reg);
+    while machine.step() {}
+    machine.out
+}
+
+pub struct Computer {
+    regs: EnumMap<Reg, u64>,
+    code: Vec<u64>,
+    prog: Vec<Op>,
+}
+
+pub fn decode(code: &[u64]) -> Option<Vec<Op>> {
+    if !code.len().is_multiple_of(2) || code.iter().any(|v| *v > 7) {
+        return None;
+    }
+    code.iter()
+        .map(|v| *v as u32)
+        .tuples()
+        .map(|(opcode, operand)| Op::decode(opcode, operand))
+        .collect()
+}
+
+// Real programs halt after a few hundred steps, without a limit of its own a
+// trace stops here.
+const TRACE_STEP_LIMIT: usize = 100_000;
+
+// Execution log until the program halts or `debugger` stops it.
+pub fn trace_with(c: &Computer, debugger: &Debugger) -> String {
+    let mut log = String::new();
+    let mut machine = Machine::new(&c.prog, c.regs);
+    let debugger = Debugger {
+        breakpoints: debugger.breakpoints.clone(),
+        step_limit: Some(debugger.step_limit.unwrap_or(TRACE_STEP_LIMIT)),
+    };
+    let stop = machine.run(&debugger, |m| {
+        log.push_str(&debug::trace_line(m));
+        log.push('\n');
+    });
+    match stop {
+        Stop::Halted => {}
+        Stop::Breakpoint(_) => {
+            log.push_str(&format!(
+                "stopped at breakpoint: {}\n",
+                debug::trace_line(&machine)
+            ));
+        }
+        Stop::StepLimit => log.push_str(&format!("stopped after {} steps\n", machine.steps)),
+    }
+    log
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = Computer;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let re = Regex::new(r"R.* A: (\d+)\nR.* B: (\d+)\nR.* C: (\d+)\n\nP.*: ([0-9,]+)").unwrap();
+        let c = re.captures(input).ok_or_else(|| {
+            ParseError::new(input, input, "Register A/B/C values and the Program")
+        })?;
+        let regs = enum_map! {
+            Reg::A => parse::num(input, &c[1])?,
+            Reg::B => parse::num(input, &c[2])?,
+            Reg::C => parse::num(input, &c[3])?,
+        };
+        let code: Vec<u64> = c[4]
+            .split(",")
+            .map(|v| parse::num(input, v))
+            .collect::<ParseResult<_>>()?;
+        let prog = decode(&code).ok_or_else(|| ParseError::new(input, &c[4], "3-bit program"))?;
+        Ok(Computer { regs, code, prog })
+    }
+
+    fn part1(c: &Self::Input) -> Answer {
+        let out = Compiled::new(&c.prog).output(c.regs);
+        out.iter().map(|v| v.to_string()).join(",").into()
+    }
+
+    fn trace(c: &Self::Input) -> Option<String> {
+        Some(trace_with(c, &Debugger::default()))
+    }
+
+    fn part2(c: &Self::Input) -> Answer {
+        match quine::solve(&c.prog, &c.code) {
+            Ok(found) => match found.first() {
+                Some(a) => (*a).into(),
+                None => Answer::Unsolved("no A outputs the program".to_string()),
+            },
+            Err(e) => Answer::Unsolved(e.to_string()),
+        }
+    }
+}
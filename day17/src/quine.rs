@@ -0,0 +1,173 @@
+use crate::fast::Compiled;
+use crate::{Combo, Op, Reg};
+use enum_map::{enum_map, EnumMap};
+use std::fmt::Display;
+
+// Programs the solver understands: a single loop ending with "jnz 0", which
+// shifts A right by a constant number of bits and writes nothing else to A,
+// with B and C written before they're read, so every iteration depends only
+// on A, and outputs the same number of values.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Shape {
+    pub shift: u32,
+    pub outs: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Unsupported {
+    Loop,
+    Shift,
+    State(Reg),
+    NoOutput,
+    Length(usize, usize),
+}
+
+impl Display for Unsupported {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unsupported::Loop => write!(f, "program isn't a single loop ending with jnz 0"),
+            Unsupported::Shift => write!(f, "A isn't only shifted by a constant once per loop"),
+            Unsupported::State(r) => write!(f, "{r:?} is read before written in the loop"),
+            Unsupported::NoOutput => write!(f, "loop doesn't output anything"),
+            Unsupported::Length(len, outs) => write!(
+                f,
+                "{len} outputs aren't a multiple of the {outs} outputs per loop"
+            ),
+        }
+    }
+}
+
+fn reads(op: &Op) -> Vec<Reg> {
+    let combo = |c: &Combo| match c {
+        Combo::Reg(r) => vec![*r],
+        Combo::Val(_) => vec![],
+    };
+    match op {
+        Op::Div(c, _) => [vec![Reg::A], combo(c)].concat(),
+        Op::Bxl(_) => vec![Reg::B],
+        Op::Bst(c) | Op::Out(c) => combo(c),
+        Op::Jnz(_) => vec![Reg::A],
+        Op::Bxc(_) => vec![Reg::B, Reg::C],
+    }
+}
+
+fn writes(op: &Op) -> Option<Reg> {
+    match op {
+        Op::Div(_, r) => Some(*r),
+        Op::Bxl(_) | Op::Bst(_) | Op::Bxc(_) => Some(Reg::B),
+        Op::Jnz(_) | Op::Out(_) => None,
+    }
+}
+
+pub fn analyse(prog: &[Op]) -> Result<Shape, Unsupported> {
+    let Some((Op::Jnz(0), body)) = prog.split_last() else {
+        return Err(Unsupported::Loop);
+    };
+    let mut shift = None;
+    let mut written: EnumMap<Reg, bool> = EnumMap::default();
+    written[Reg::A] = true;
+    for op in body {
+        if let Some(r) = reads(op).into_iter().find(|r| !written[*r]) {
+            return Err(Unsupported::State(r));
+        }
+        match (op, writes(op)) {
+            (Op::Jnz(_), _) => return Err(Unsupported::Loop),
+            (Op::Div(Combo::Val(k @ 1..), Reg::A), _) if shift.is_none() => shift = Some(*k as u32),
+            (_, Some(Reg::A)) => return Err(Unsupported::Shift),
+            (_, Some(r)) => written[r] = true,
+            (_, None) => {}
+        }
+    }
+    let outs = body.iter().filter(|op| matches!(op, Op::Out(_))).count();
+    if outs == 0 {
+        return Err(Unsupported::NoOutput);
+    }
+    Ok(Shape {
+        shift: shift.ok_or(Unsupported::Shift)?,
+        outs,
+    })
+}
+
+struct Solver<'a> {
+    body: Compiled,
+    shape: Shape,
+    expected: &'a [u64],
+    found: Vec<u64>,
+}
+
+impl Solver<'_> {
+    // A for the loop `iter` is the one of the next loop with `shift` more bits
+    // at the bottom, the last loop has to leave 0 behind.
+    fn solve(&mut self, iter: usize, next_a: u64) {
+        let outs = &self.expected[iter * self.shape.outs..(iter + 1) * self.shape.outs];
+        for low in 0..1 << self.shape.shift {
+            let a = next_a << self.shape.shift | low;
+            // Only the very first loop runs without a non zero A.
+            if a == 0 && self.expected.len() > self.shape.outs {
+                continue;
+            }
+            let regs = enum_map! { Reg::A => a, Reg::B => 0, Reg::C => 0 };
+            let mut rest = outs.iter();
+            let mut ok = true;
+            self.body.run(regs, |v| {
+                ok = rest.next() == Some(&v);
+                ok
+            });
+            if ok && rest.next().is_none() {
+                if iter == 0 {
+                    self.found.push(a);
+                } else {
+                    self.solve(iter - 1, a);
+                }
+            }
+        }
+    }
+}
+
+// Every initial A for which the program outputs `expected`, in order.
+pub fn solve(prog: &[Op], expected: &[u64]) -> Result<Vec<u64>, Unsupported> {
+    let shape = analyse(prog)?;
+    if !expected.len().is_multiple_of(shape.outs) {
+        return Err(Unsupported::Length(expected.len(), shape.outs));
+    }
+    if expected.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut solver = Solver {
+        body: Compiled::new(&prog[..prog.len() - 1]),
+        shape,
+        expected,
+        found: Vec::new(),
+    };
+    solver.solve(expected.len() / shape.outs - 1, 0);
+    solver.found.sort();
+    Ok(solver.found)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::asm::assemble;
+
+    #[test]
+    fn quines() {
+        let prog = assemble("adv 3\nout A\njnz 0").unwrap();
+        assert_eq!(analyse(&prog), Ok(Shape { shift: 3, outs: 1 }));
+        // The lowest 3 bits are shifted out before anything is output.
+        let all: Vec<u64> = (117440..117448).collect();
+        assert_eq!(solve(&prog, &[0, 3, 5, 4, 3, 0]), Ok(all));
+
+        // Two bits per loop and two outputs.
+        let prog = assemble("bst A\nout B\nadv 2\nbxl 1\nout B\njnz 0").unwrap();
+        assert_eq!(analyse(&prog), Ok(Shape { shift: 2, outs: 2 }));
+        let found = solve(&prog, &[6, 7, 1, 0]).unwrap();
+        assert_eq!(found, vec![0b0110]);
+
+        let check = |text: &str, err| assert_eq!(analyse(&assemble(text).unwrap()), Err(err));
+        check("adv 3\nout A\njnz 2", Unsupported::Loop);
+        check("adv A\nout A\njnz 0", Unsupported::Shift);
+        check("adv 1\nadv 1\nout A\njnz 0", Unsupported::Shift);
+        check("bxl 1\nadv 3\nout B\njnz 0", Unsupported::State(Reg::B));
+        check("adv 3\njnz 0", Unsupported::NoOutput);
+    }
+}
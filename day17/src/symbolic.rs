@@ -0,0 +1,412 @@
+use crate::{Combo, Op, Reg};
+use enum_map::EnumMap;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+// Bits are expressions over the bits of the unknown initial A, hash consed
+// in an arena so the common subexpressions are shared.
+type Bit = usize;
+type Word = [Bit; 64];
+
+const FALSE: Bit = 0;
+const TRUE: Bit = 1;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Expr {
+    Const(bool),
+    Var(u32),
+    Not(Bit),
+    And(Bit, Bit),
+    Or(Bit, Bit),
+    Xor(Bit, Bit),
+}
+
+struct Exprs {
+    nodes: Vec<Expr>,
+    index: HashMap<Expr, Bit>,
+}
+
+impl Exprs {
+    fn new() -> Exprs {
+        let mut exprs = Exprs {
+            nodes: Vec::new(),
+            index: HashMap::new(),
+        };
+        exprs.add(Expr::Const(false));
+        exprs.add(Expr::Const(true));
+        exprs
+    }
+
+    fn add(&mut self, e: Expr) -> Bit {
+        if let Some(b) = self.index.get(&e) {
+            return *b;
+        }
+        self.nodes.push(e);
+        self.index.insert(e, self.nodes.len() - 1);
+        self.nodes.len() - 1
+    }
+
+    fn constant(&self, v: bool) -> Bit {
+        if v {
+            TRUE
+        } else {
+            FALSE
+        }
+    }
+
+    fn not(&mut self, a: Bit) -> Bit {
+        match self.nodes[a] {
+            Expr::Const(v) => self.constant(!v),
+            Expr::Not(x) => x,
+            _ => self.add(Expr::Not(a)),
+        }
+    }
+
+    fn and(&mut self, a: Bit, b: Bit) -> Bit {
+        match (a, b) {
+            (FALSE, _) | (_, FALSE) => FALSE,
+            (TRUE, x) | (x, TRUE) => x,
+            _ if a == b => a,
+            _ => self.add(Expr::And(a.min(b), a.max(b))),
+        }
+    }
+
+    fn or(&mut self, a: Bit, b: Bit) -> Bit {
+        match (a, b) {
+            (TRUE, _) | (_, TRUE) => TRUE,
+            (FALSE, x) | (x, FALSE) => x,
+            _ if a == b => a,
+            _ => self.add(Expr::Or(a.min(b), a.max(b))),
+        }
+    }
+
+    fn xor(&mut self, a: Bit, b: Bit) -> Bit {
+        match (a, b) {
+            (FALSE, x) | (x, FALSE) => x,
+            (TRUE, x) | (x, TRUE) => self.not(x),
+            _ if a == b => FALSE,
+            _ => self.add(Expr::Xor(a.min(b), a.max(b))),
+        }
+    }
+
+    fn word(&self, v: u64) -> Word {
+        std::array::from_fn(|i| self.constant(v >> i & 1 == 1))
+    }
+
+    fn value(&self, w: &[Bit]) -> Option<u64> {
+        w.iter()
+            .enumerate()
+            .try_fold(0, |acc, (i, b)| match self.nodes[*b] {
+                Expr::Const(v) => Some(acc | (v as u64) << i),
+                _ => None,
+            })
+    }
+
+    // Conditions for `w` being equal to `v`, one per bit.
+    fn equal(&mut self, w: &[Bit], v: u64) -> Vec<Bit> {
+        w.iter()
+            .enumerate()
+            .map(|(i, b)| if v >> i & 1 == 1 { *b } else { self.not(*b) })
+            .collect()
+    }
+
+    fn shr(&mut self, x: &Word, s: &Word) -> Result<Word, Unsolvable> {
+        // Nothing is left of x after a shift by its width or more.
+        if let Some(s) = self.value(s) {
+            if s >= 64 {
+                return Ok([FALSE; 64]);
+            }
+            return Ok(std::array::from_fn(|i| {
+                x.get(i + s as usize).copied().unwrap_or(FALSE)
+            }));
+        }
+        // Shift by every value `s` can have, guarded by `s` having it.
+        let unknown: Vec<usize> = (0..64)
+            .filter(|i| self.value(&s[*i..*i + 1]).is_none())
+            .collect();
+        if unknown.len() > 6 {
+            return Err(Unsolvable::Shift);
+        }
+        let known =
+            std::array::from_fn::<_, 64, _>(|i| if unknown.contains(&i) { FALSE } else { s[i] });
+        let known = self.value(&known).unwrap();
+        let mut res = [FALSE; 64];
+        for case in 0..1u64 << unknown.len() {
+            let mut shift = known;
+            let mut guard = TRUE;
+            for (j, i) in unknown.iter().enumerate() {
+                let bit = case >> j & 1;
+                shift |= bit << i;
+                let cond = if bit == 1 { s[*i] } else { self.not(s[*i]) };
+                guard = self.and(guard, cond);
+            }
+            if shift >= 64 {
+                continue;
+            }
+            for (i, r) in res.iter_mut().enumerate() {
+                if let Some(b) = x.get(i + shift as usize) {
+                    let b = self.and(guard, *b);
+                    *r = self.or(*r, b);
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    // Conjunctions are split, the parts can be checked separately.
+    fn split(&mut self, b: Bit, out: &mut Vec<Bit>) {
+        match self.nodes[b] {
+            Expr::And(x, y) => {
+                self.split(x, out);
+                self.split(y, out);
+            }
+            Expr::Not(n) => match self.nodes[n] {
+                Expr::Or(x, y) => {
+                    let (x, y) = (self.not(x), self.not(y));
+                    self.split(x, out);
+                    self.split(y, out);
+                }
+                _ => out.push(b),
+            },
+            _ => out.push(b),
+        }
+    }
+
+    fn max_var(&self, b: Bit, memo: &mut HashMap<Bit, Option<u32>>) -> Option<u32> {
+        if let Some(m) = memo.get(&b) {
+            return *m;
+        }
+        let m = match self.nodes[b] {
+            Expr::Const(_) => None,
+            Expr::Var(i) => Some(i),
+            Expr::Not(x) => self.max_var(x, memo),
+            Expr::And(x, y) | Expr::Or(x, y) | Expr::Xor(x, y) => {
+                self.max_var(x, memo).max(self.max_var(y, memo))
+            }
+        };
+        memo.insert(b, m);
+        m
+    }
+
+    fn eval(&self, b: Bit, vars: &[bool]) -> bool {
+        match self.nodes[b] {
+            Expr::Const(v) => v,
+            Expr::Var(i) => vars[i as usize],
+            Expr::Not(x) => !self.eval(x, vars),
+            Expr::And(x, y) => self.eval(x, vars) && self.eval(y, vars),
+            Expr::Or(x, y) => self.eval(x, vars) || self.eval(y, vars),
+            Expr::Xor(x, y) => self.eval(x, vars) ^ self.eval(y, vars),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Unsolvable {
+    Shift,
+    StepLimit,
+}
+
+impl Display for Unsolvable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Unsolvable::Shift => write!(f, "shift by a value with too many unknown bits"),
+            Unsolvable::StepLimit => write!(f, "program runs for too long"),
+        }
+    }
+}
+
+fn combo(exprs: &Exprs, regs: &EnumMap<Reg, Word>, c: Combo) -> Word {
+    match c {
+        Combo::Val(v) => exprs.word(v),
+        Combo::Reg(r) => regs[r],
+    }
+}
+
+const STEP_LIMIT: usize = 100_000;
+
+#[derive(Clone)]
+struct State {
+    ip: usize,
+    regs: EnumMap<Reg, Word>,
+    outs: usize,
+    constraints: Vec<Bit>,
+}
+
+// Runs the program on every path its jumps can take, the constraints of the
+// paths that output exactly `target`.
+fn execute(
+    exprs: &mut Exprs,
+    prog: &[Op],
+    start: State,
+    target: &[u64],
+) -> Result<Vec<Vec<Bit>>, Unsolvable> {
+    let mut paths = Vec::new();
+    let mut stack = vec![start];
+    let mut steps = 0;
+    'paths: while let Some(mut state) = stack.pop() {
+        while let Some(op) = prog.get(state.ip) {
+            steps += 1;
+            if steps > STEP_LIMIT {
+                return Err(Unsolvable::StepLimit);
+            }
+            let regs = &mut state.regs;
+            match *op {
+                Op::Div(c, r) => {
+                    let s = combo(exprs, regs, c);
+                    regs[r] = exprs.shr(&regs[Reg::A], &s)?;
+                }
+                Op::Bxl(l) => {
+                    for i in 0..3 {
+                        if l >> i & 1 == 1 {
+                            regs[Reg::B][i] = exprs.not(regs[Reg::B][i]);
+                        }
+                    }
+                }
+                Op::Bst(c) => {
+                    let v = combo(exprs, regs, c);
+                    regs[Reg::B] = [FALSE; 64];
+                    regs[Reg::B][..3].copy_from_slice(&v[..3]);
+                }
+                Op::Jnz(p) => {
+                    let nonzero = regs[Reg::A].iter().fold(FALSE, |acc, b| exprs.or(acc, *b));
+                    if nonzero != FALSE {
+                        if nonzero != TRUE {
+                            let mut halt = state.clone();
+                            let zero = exprs.not(nonzero);
+                            exprs.split(zero, &mut halt.constraints);
+                            halt.ip += 1;
+                            stack.push(halt);
+                            state.constraints.push(nonzero);
+                        }
+                        state.ip = p;
+                        continue;
+                    }
+                }
+                Op::Bxc(_) => {
+                    for i in 0..64 {
+                        regs[Reg::B][i] = exprs.xor(regs[Reg::B][i], regs[Reg::C][i]);
+                    }
+                }
+                Op::Out(c) => {
+                    let Some(expected) = target.get(state.outs) else {
+                        continue 'paths;
+                    };
+                    let v = combo(exprs, regs, c);
+                    for b in exprs.equal(&v[..3], *expected) {
+                        if b == FALSE {
+                            continue 'paths;
+                        }
+                        exprs.split(b, &mut state.constraints);
+                    }
+                    state.outs += 1;
+                }
+            }
+            state.ip += 1;
+        }
+        if state.outs == target.len() {
+            paths.push(state.constraints);
+        }
+    }
+    Ok(paths)
+}
+
+// Backtracking over the bits of A from the lowest, every constraint is
+// checked as soon as the highest bit it depends on is known.
+fn assign(exprs: &Exprs, by_var: &[Vec<Bit>], vars: &mut Vec<bool>, found: &mut Vec<u64>) {
+    if vars.len() == by_var.len() {
+        found.push(vars.iter().rev().fold(0, |acc, b| acc << 1 | *b as u64));
+        return;
+    }
+    for v in [false, true] {
+        vars.push(v);
+        if by_var[vars.len() - 1].iter().all(|c| exprs.eval(*c, vars)) {
+            assign(exprs, by_var, vars, found);
+        }
+        vars.pop();
+    }
+}
+
+fn assignments(exprs: &Exprs, constraints: &[Bit], bits: u32) -> Vec<u64> {
+    let mut memo = HashMap::new();
+    let mut by_var: Vec<Vec<Bit>> = vec![Vec::new(); bits as usize];
+    for c in constraints {
+        match exprs.max_var(*c, &mut memo) {
+            Some(v) => by_var[v as usize].push(*c),
+            None if *c == FALSE => return Vec::new(),
+            None => {}
+        }
+    }
+    let mut found = Vec::new();
+    assign(exprs, &by_var, &mut Vec::new(), &mut found);
+    found
+}
+
+// Every A below 2^bits for which the program outputs `target`, with B and C
+// starting as in `regs`.
+pub fn solve(
+    prog: &[Op],
+    regs: EnumMap<Reg, u64>,
+    target: &[u64],
+    bits: u32,
+) -> Result<Vec<u64>, Unsolvable> {
+    let mut exprs = Exprs::new();
+    let a = std::array::from_fn(|i| {
+        if i < bits as usize {
+            exprs.add(Expr::Var(i as u32))
+        } else {
+            FALSE
+        }
+    });
+    let start = State {
+        ip: 0,
+        regs: EnumMap::from_fn(|r| match r {
+            Reg::A => a,
+            _ => exprs.word(regs[r]),
+        }),
+        outs: 0,
+        constraints: Vec::new(),
+    };
+    let mut found = Vec::new();
+    for constraints in execute(&mut exprs, prog, start, target)? {
+        found.extend(assignments(&exprs, &constraints, bits));
+    }
+    found.sort();
+    found.dedup();
+    Ok(found)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{decode, quine, simulate};
+    use enum_map::enum_map;
+
+    #[test]
+    fn solve_outputs() {
+        let code = [2, 4, 1, 5, 7, 5, 1, 6, 0, 3, 4, 0, 5, 5, 3, 0];
+        let prog = decode(&code).unwrap();
+        let regs = enum_map! { Reg::A => 0, Reg::B => 0, Reg::C => 0 };
+        let found = solve(&prog, regs, &code, 48).unwrap();
+        assert_eq!(found, quine::solve(&prog, &code).unwrap());
+
+        // Any output, not only the program itself.
+        let run = |a| simulate(enum_map! { Reg::A => a, Reg::B => 0, Reg::C => 0 }, &prog);
+        let target = run(2024);
+        let expected: Vec<u64> = (0..1 << 12).filter(|a| run(*a) == target).collect();
+        assert_eq!(solve(&prog, regs, &target, 12).unwrap(), expected);
+
+        let prog = decode(&[0, 4, 5, 4, 3, 0]).unwrap();
+        assert_eq!(solve(&prog, regs, &[1], 8), Err(Unsolvable::Shift));
+
+        // adv B, out A with a known B far past the width of A.
+        let prog = decode(&[0, 5, 5, 4]).unwrap();
+        for b in [64, 1 << 40, u64::MAX] {
+            let regs = enum_map! { Reg::A => 0, Reg::B => b, Reg::C => 0 };
+            assert_eq!(
+                solve(&prog, regs, &[0], 4).unwrap(),
+                (0..16).collect::<Vec<u64>>()
+            );
+            assert_eq!(solve(&prog, regs, &[1], 4).unwrap(), vec![]);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use aoc::grid::{Grid, Pos};
+use aoc::parse::{self, ParseError};
+use aoc::search;
+use aoc::solution::{Answer, ParseResult, Solution};
+use aoc::unionfind::UnionFind;
+
+const DIRS: [(i32, i32); 4] = [(1, 0), (-1, 0), (0, 1), (0, -1)];
+const DIRS8: [(i32, i32); 8] = [
+    (1, 0),
+    (-1, 0),
+    (0, 1),
+    (0, -1),
+    (1, 1),
+    (1, -1),
+    (-1, 1),
+    (-1, -1),
+];
+
+// Grid of the fallen bytes.
+struct Memory<'a>(&'a Grid<bool>);
+
+impl search::Graph for Memory<'_> {
+    type Node = Pos;
+    type Cost = usize;
+
+    fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Pos, usize)> {
+        self.0
+            .neighbours(DIRS.into_iter(), pos)
+            .filter(|npos| !self.0[*npos])
+            .map(|npos| (npos, 1))
+    }
+}
+
+const SIZE: usize = 71;
+const STEPS: usize = 1024;
+
+// Steps to the exit of a size x size grid once the first `steps` bytes fell.
+fn shortest_path(bytes: &[Pos], size: usize, steps: usize) -> Option<usize> {
+    let mut grid = Grid::new(size, size, false);
+    for pos in bytes.iter().take(steps) {
+        grid[*pos] = true;
+    }
+
+    let exit = Pos::new(size as i32 - 1, size as i32 - 1);
+    search::bfs(&Memory(&grid), Pos::new(0, 0), |pos| pos == exit).end()
+}
+
+// The exit gets cut off once the fallen bytes (touching diagonally too)
+// connect the bottom or left edge with the top or right edge.
+fn first_blocking(bytes: &[Pos], size: usize) -> Option<Pos> {
+    let idx = |p: Pos| p.y as usize * size + p.x as usize;
+    let bottom_left = size * size;
+    let top_right = size * size + 1;
+
+    let mut blocked = Grid::new(size, size, false);
+    let mut uf = UnionFind::new(size * size + 2);
+
+    for pos in bytes.iter() {
+        // Falling on a byte already there changes nothing.
+        if blocked[*pos] {
+            continue;
+        }
+        blocked[*pos] = true;
+        if pos.x == 0 || pos.y == size as i32 - 1 {
+            uf.union(idx(*pos), bottom_left);
+        }
+        if pos.y == 0 || pos.x == size as i32 - 1 {
+            uf.union(idx(*pos), top_right);
+        }
+        for npos in blocked.neighbours(DIRS8.into_iter(), *pos) {
+            if blocked[npos] {
+                uf.union(idx(*pos), idx(npos));
+            }
+        }
+
+        if uf.same(bottom_left, top_right) {
+            return Some(*pos);
+        }
+    }
+    None
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = Vec<Pos>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .lines()
+            .map(|l| {
+                let (x, y) = parse::split_once(input, l, ",")?;
+                let coord = |s| {
+                    let v = parse::num(input, s)?;
+                    if (0..SIZE as i32).contains(&v) {
+                        Ok(v)
+                    } else {
+                        Err(ParseError::new(input, s, format!("0-{}", SIZE - 1)))
+                    }
+                };
+                Ok(Pos::new(coord(x)?, coord(y)?))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        match shortest_path(input, SIZE, STEPS) {
+            Some(steps) => steps.into(),
+            None => Answer::Unsolved("no path to the exit".to_string()),
+        }
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        match first_blocking(input, SIZE) {
+            Some(pos) => format!("{},{}", pos.x, pos.y).into(),
+            None => Answer::Unsolved("the exit is never blocked".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        // The example is a 7x7 grid with only the first 12 bytes fallen.
+        let input = Day18::parse(include_str!("../../examples/day18.txt")).unwrap();
+        assert_eq!(shortest_path(&input, 7, 12), Some(22));
+        assert_eq!(first_blocking(&input, 7), Some(Pos::new(6, 1)));
+    }
+
+    #[test]
+    fn odd_inputs() {
+        let err = Day18::parse("1,2\n1,-1\n").err().unwrap();
+        assert_eq!(
+            (err.line, err.column, err.expected.as_str()),
+            (2, 3, "0-70")
+        );
+        assert!(Day18::parse("71,0\n").is_err());
+
+        let input = Day18::parse("").unwrap();
+        assert_eq!(Day18::part1(&input), Answer::Num(140));
+        let input = Day18::parse("1,0\n1,0\n").unwrap();
+        assert_eq!(
+            Day18::part2(&input),
+            Answer::Unsolved("the exit is never blocked".to_string())
+        );
+    }
+}
@@ -0,0 +1,57 @@
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, ParseResult, Solution};
+use regex::Regex;
+use std::cmp::min;
+
+fn num_matches(patterns: &[&[u8]], design: &[u8]) -> u64 {
+    if design.is_empty() {
+        return 0;
+    }
+
+    let mut combi = vec![0; design.len() + 1];
+    combi[design.len()] = 1;
+
+    for i in (0..design.len()).rev() {
+        for p in patterns {
+            if design[i..min(i + p.len(), design.len())] == **p {
+                combi[i] += combi[i + p.len()];
+            }
+        }
+    }
+
+    combi[0]
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = (Vec<String>, Vec<String>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut lines = input.lines();
+        let patterns = lines
+            .next()
+            .ok_or_else(|| ParseError::new(input, input, "towel patterns"))?
+            .trim()
+            .split(", ")
+            .map(String::from)
+            .collect();
+        Ok((patterns, lines.map(String::from).collect()))
+    }
+
+    fn part1((patterns, designs): &Self::Input) -> Answer {
+        let re_str = format!("^({})+$", patterns.join("|"));
+        let re = Regex::new(&re_str).unwrap();
+        designs.iter().filter(|l| re.is_match(l)).count().into()
+    }
+
+    fn part2((patterns, designs): &Self::Input) -> Answer {
+        let patterns: Vec<&[u8]> = patterns.iter().map(|s| s.as_bytes()).collect();
+        let res: u64 = designs
+            .iter()
+            .map(|l| num_matches(patterns.as_slice(), l.as_bytes()))
+            .sum();
+        res.into()
+    }
+}
@@ -0,0 +1,57 @@
+use aoc::parse;
+use aoc::solution::{Answer, ParseResult, Solution};
+
+fn is_safe(l: &[i64], ignore_idx: usize) -> bool {
+    let mut prev = -1;
+    let mut dir = 0;
+    for (i, v) in l.iter().enumerate() {
+        if ignore_idx == i {
+            continue;
+        }
+        if prev != -1 {
+            let diff = prev - v;
+            if dir == 0 {
+                dir = diff.signum();
+            }
+            if diff.signum() != dir || 1 > diff.abs() || diff.abs() > 3 {
+                return false;
+            }
+        }
+        prev = *v
+    }
+    true
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|x| parse::num(input, x))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        reports
+            .iter()
+            .filter(|l| is_safe(l, l.len()))
+            .count()
+            .into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        reports
+            .iter()
+            .filter(|l| (0..l.len() + 1).any(|i| is_safe(l, i)))
+            .count()
+            .into()
+    }
+}
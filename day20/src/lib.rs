@@ -0,0 +1,155 @@
+use aoc::grid::{self, Grid, Pos};
+use aoc::parse::ParseError;
+use aoc::search;
+use aoc::solution::{Answer, ParseResult, Solution};
+use enum_iterator::{all, Sequence};
+use std::iter;
+
+#[derive(Sequence)]
+enum Dir {
+    West,
+    East,
+    North,
+    South,
+}
+
+impl grid::Dir for Dir {
+    fn vector(&self) -> (i32, i32) {
+        match self {
+            Dir::West => (-1, 0),
+            Dir::East => (1, 0),
+            Dir::South => (0, 1),
+            Dir::North => (0, -1),
+        }
+    }
+}
+
+fn circle(size: i32) -> impl Iterator<Item = (i32, i32)> {
+    let mut x = -size;
+    let mut y = 0;
+    iter::from_fn(move || {
+        while x <= size {
+            while y <= (size - x.abs()) {
+                let ry = y;
+                y += 1;
+                if x != 0 || ry != 0 {
+                    return Some((x, ry));
+                }
+            }
+            x += 1;
+            y = -(size - x.abs());
+        }
+        None
+    })
+}
+
+struct Track<'a>(&'a Grid<u8>);
+
+impl search::Graph for Track<'_> {
+    type Node = Pos;
+    type Cost = usize;
+
+    fn neighbours(&self, pos: Pos) -> impl Iterator<Item = (Pos, usize)> {
+        self.0
+            .neighbours(all::<Dir>(), pos)
+            .filter(|npos| self.0[*npos] != b'#')
+            .map(|npos| (npos, 1))
+    }
+}
+
+fn bfs(grid: &Grid<u8>, start: Pos) -> Grid<i32> {
+    let paths = search::bfs(&Track(grid), start, |_| false);
+    Grid::new_with(grid.width(), grid.height(), |x, y| {
+        let pos = Pos::new(x as i32, y as i32);
+        paths.dist(pos).map_or(i32::MAX, |d| d as i32)
+    })
+}
+
+// Cheats of up to 2 steps are the same as the neighbours in circle(2).
+fn count_cheats(
+    (grid, start, end): &(Grid<u8>, Pos, Pos),
+    max_cheat: i32,
+    min_saving: i32,
+) -> usize {
+    let start_dist = bfs(grid, *start);
+    let end_dist = bfs(grid, *end);
+
+    let full_dist = start_dist[*end];
+    let mut count = 0;
+    for pos in grid.iter().filter(|pos| grid[*pos] != b'#') {
+        for npos in grid
+            .neighbours(circle(max_cheat), pos)
+            .filter(|npos| grid[*npos] != b'#')
+        {
+            let new_dist = start_dist[pos] + end_dist[npos] + pos.manhattan(npos);
+            if new_dist <= full_dist - min_saving {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Input = (Grid<u8>, Pos, Pos);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let grid: Grid<u8> = input.parse()?;
+        let find = |c: u8, what: &str| {
+            grid.find(&c)
+                .ok_or_else(|| ParseError::new(input, &input[input.len()..], what))
+        };
+        let start = find(b'S', "start tile 'S'")?;
+        let end = find(b'E', "end tile 'E'")?;
+        Ok((grid, start, end))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        count_cheats(input, 2, 100).into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        count_cheats(input, 20, 100).into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn circle_test() {
+        let mut v: Vec<(i32, i32)> = circle(2).collect();
+        let mut expected = vec![
+            (-2, 0),
+            (-1, -1),
+            (-1, 0),
+            (-1, 1),
+            (0, -2),
+            (0, -1),
+            (0, 1),
+            (0, 2),
+            (1, -1),
+            (1, 0),
+            (1, 1),
+            (2, 0),
+        ];
+        v.sort();
+        expected.sort();
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn example() {
+        // The example track is tiny, its cheats count from a saving of 50.
+        let input = Day20::parse(include_str!("../../examples/day20.txt")).unwrap();
+        assert_eq!(count_cheats(&input, 2, 50), 1);
+        assert_eq!(count_cheats(&input, 20, 50), 285);
+
+        let err = Day20::parse("#####\n#..E#\n#####\n").err().unwrap();
+        assert_eq!(err.expected, "start tile 'S'");
+    }
+}
@@ -1,6 +1,7 @@
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, ParseResult, Solution};
 use std::collections::HashMap;
 use std::fmt::Display;
-use std::io;
 
 trait Key {
     fn key_pos(&self) -> (i32, i32);
@@ -65,12 +66,14 @@ fn append_seq(seq: &mut Vec<DirKey>, s: (u64, DirKey)) {
     }
 }
 
+type StepsCache = HashMap<(u32, (u64, DirKey), (u64, DirKey), u64), u64>;
+
 fn steps(
     level: u32,
     s1: (u64, DirKey),
     s2: (u64, DirKey),
     an: u64,
-    cache: &mut HashMap<(u32, (u64, DirKey), (u64, DirKey), u64), u64>,
+    cache: &mut StepsCache,
     build_seq: bool,
 ) -> (u64, Vec<DirKey>) {
     if level == 0 {
@@ -119,7 +122,7 @@ fn steps(
 }
 
 #[derive(Clone, Copy, Debug)]
-enum LockKey {
+pub enum LockKey {
     K0,
     K1,
     K2,
@@ -185,7 +188,10 @@ fn key_steps<K: Key>(a: K, b: K) -> Vec<((u64, DirKey), (u64, DirKey))> {
     };
     let dy = if d.1 >= 0 { DirKey::Down } else { DirKey::Up };
 
-    let res1 = ((d.0.abs() as u64, dx), (d.1.abs() as u64, dy));
+    let res1 = (
+        (d.0.unsigned_abs() as u64, dx),
+        (d.1.unsigned_abs() as u64, dy),
+    );
     let res2 = (res1.1, res1.0);
 
     let mut res = Vec::with_capacity(2);
@@ -218,25 +224,49 @@ fn sequence(code: &[LockKey], robots: u32, build_seq: bool) -> (u64, Vec<DirKey>
     (res, res_seq)
 }
 
-fn print_sequence(sequence: &Vec<DirKey>) {
-    for c in sequence {
-        print!("{c}");
+fn complexity(codes: &[(Vec<LockKey>, u64)], robots: u32) -> u64 {
+    let mut res = 0;
+    for (code, code_val) in codes {
+        let (len, _) = sequence(code.as_slice(), robots, false);
+        res += code_val * len;
     }
-    println!("");
+    res
 }
 
-fn main() {
-    let mut res = 0;
-    for line in io::stdin().lines() {
-        let line = line.unwrap();
-        let code: Vec<LockKey> = line.bytes().map(|c| LockKey::from_u8(c).unwrap()).collect();
-        let code_val: u64 = line.strip_suffix("A").unwrap().parse().unwrap();
-        let (len, seq) = sequence(code.as_slice(), 25, false);
-        res += code_val * len;
-        print!("{}: {} ", line, len);
-        print_sequence(&seq);
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Input = Vec<(Vec<LockKey>, u64)>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let code = line
+                    .char_indices()
+                    .map(|(i, c)| {
+                        u8::try_from(c)
+                            .ok()
+                            .and_then(LockKey::from_u8)
+                            .ok_or_else(|| ParseError::new(input, &line[i..], "keypad key"))
+                    })
+                    .collect::<ParseResult<_>>()?;
+                let code_val = line
+                    .strip_suffix("A")
+                    .ok_or_else(|| ParseError::new(input, &line[line.len()..], "'A'"))?;
+                Ok((code, parse::num(input, code_val)?))
+            })
+            .collect()
+    }
+
+    fn part1(codes: &Self::Input) -> Answer {
+        complexity(codes, 2).into()
+    }
+
+    fn part2(codes: &Self::Input) -> Answer {
+        complexity(codes, 25).into()
     }
-    println!("{}", res);
 }
 
 #[cfg(test)]
@@ -0,0 +1,84 @@
+use aoc::parse;
+use aoc::solution::{Answer, ParseResult, Solution};
+use itertools::Itertools;
+
+fn prng(mut n: u64) -> u64 {
+    let m = (1 << 24) - 1;
+    n ^= (n << 6) & m;
+    n ^= n >> 5;
+    n ^= (n << 11) & m;
+    n
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .trim()
+            .split("\n")
+            .map(|v| parse::num(input, v))
+            .collect()
+    }
+
+    fn part1(secrets: &Self::Input) -> Answer {
+        let res: u64 = secrets
+            .iter()
+            .map(|v| (0..2000).fold(*v, |n, _| prng(n)))
+            .sum();
+        res.into()
+    }
+
+    fn part2(secrets: &Self::Input) -> Answer {
+        secrets
+            .iter()
+            .flat_map(|&secret| {
+                (0..2000)
+                    // build prices list
+                    .scan(secret, |n, _| {
+                        let old_n = *n;
+                        *n = prng(*n);
+                        Some((old_n % 10) as i8)
+                    })
+                    // compute (change, price_after)
+                    .tuple_windows()
+                    .map(|(a, b)| (b - a, b as u64))
+                    // build grouping pattern -> costs
+                    .tuple_windows()
+                    .map(|(p1, p2, p3, p4)| ((p1.0, p2.0, p3.0, p4.0), p4.1))
+                    .into_grouping_map()
+                    // take first cost for each pattern
+                    .reduce(|a, _key, _b| a)
+                    .into_iter()
+            })
+            .into_grouping_map()
+            .sum()
+            .into_values()
+            .max()
+            .unwrap()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_prng() {
+        let seq: Vec<u64> = (0..10)
+            .scan(123, |n, _| {
+                *n = prng(*n);
+                Some(*n)
+            })
+            .collect();
+        let expected = vec![
+            15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432,
+            5908254,
+        ];
+        assert_eq!(seq, expected);
+    }
+}
@@ -0,0 +1,52 @@
+// Time to find the largest clique of random graphs with the old clique DFS
+// and with Bron–Kerbosch, both on the same parsed input:
+// cargo run --release -p aoc2024day23 --example cliques
+use aoc::solution::Solution;
+use aoc2024day23::Day23;
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+// Every pair of `n` nodes connected with probability `p`, in the puzzle
+// format with two letter names.
+fn random_graph(n: usize, p: f64, seed: u64) -> String {
+    let mut state = seed;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    let name = |i: usize| {
+        format!(
+            "{}{}",
+            (b'a' + (i / 26) as u8) as char,
+            (b'a' + (i % 26) as u8) as char
+        )
+    };
+    let mut text = String::new();
+    for a in 0..n {
+        for b in a + 1..n {
+            if ((next() % 1_000_000) as f64) < p * 1e6 {
+                writeln!(text, "{}-{}", name(a), name(b)).unwrap();
+            }
+        }
+    }
+    text
+}
+
+fn measure(f: impl FnOnce() -> Vec<usize>) -> (usize, Duration) {
+    let start = Instant::now();
+    let clique = f();
+    (clique.len(), start.elapsed())
+}
+
+fn main() {
+    println!("nodes     p   clique          dfs    bron-kerbosch");
+    for (n, p) in [(520, 0.025), (500, 0.1), (300, 0.3), (150, 0.5), (100, 0.7)] {
+        let net = Day23::parse(&random_graph(n, p, 0x9e3779b97f4a7c15)).unwrap();
+        let (dfs_len, dfs) = measure(|| net.largest_by_dfs());
+        let (bk_len, bk) = measure(|| net.graph().maximum_clique());
+        assert_eq!(dfs_len, bk_len);
+        println!("{n:>5} {p:>5} {bk_len:>8} {dfs:>12.2?} {bk:>16.2?}");
+    }
+}
@@ -0,0 +1,259 @@
+use aoc::bitset::BitSet;
+
+// Whether a clique needs any or all of its nodes to be selected.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Mode {
+    Any,
+    All,
+}
+
+// Undirected graph with the neighbours of every node as a bitset, so the
+// candidate sets of Bron–Kerbosch are a few word operations to narrow down.
+pub struct Graph {
+    adj: Vec<BitSet>,
+}
+
+impl Graph {
+    pub fn new(edges: &[Vec<usize>]) -> Graph {
+        let adj = edges
+            .iter()
+            .map(|ns| {
+                let mut set = BitSet::new(edges.len());
+                for n in ns {
+                    set.insert(*n);
+                }
+                set
+            })
+            .collect();
+        Graph { adj }
+    }
+
+    pub fn len(&self) -> usize {
+        self.adj.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adj.is_empty()
+    }
+
+    pub fn neighbours(&self, n: usize) -> &BitSet {
+        &self.adj[n]
+    }
+
+    // Repeatedly takes out the node with the fewest neighbours left, so
+    // every node has at most the degeneracy of the graph later in the order.
+    fn degeneracy_order(&self) -> Vec<usize> {
+        let mut degree: Vec<usize> = self.adj.iter().map(|a| a.len()).collect();
+        let mut left = BitSet::full(self.len());
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(n) = left.iter().min_by_key(|n| degree[*n]) {
+            left.remove(n);
+            for m in self.adj[n].intersection(&left).iter() {
+                degree[m] -= 1;
+            }
+            order.push(n);
+        }
+        order
+    }
+
+    // Calls f once for every maximal clique, in no particular order.
+    pub fn for_each_maximal<F>(&self, mut f: F)
+    where
+        F: FnMut(&[usize]),
+    {
+        let mut p = BitSet::full(self.len());
+        let mut x = BitSet::new(self.len());
+        let mut r = Vec::new();
+        for n in self.degeneracy_order() {
+            r.push(n);
+            self.expand(
+                &mut r,
+                p.intersection(&self.adj[n]),
+                x.intersection(&self.adj[n]),
+                &mut f,
+            );
+            r.pop();
+            p.remove(n);
+            x.insert(n);
+        }
+    }
+
+    // Grows the clique `r` with the nodes of `p`, `x` being the ones that
+    // would only find cliques already reported. Neighbours of the pivot get
+    // their turn with it or after it.
+    fn expand<F>(&self, r: &mut Vec<usize>, mut p: BitSet, mut x: BitSet, f: &mut F)
+    where
+        F: FnMut(&[usize]),
+    {
+        let Some(pivot) = p
+            .union(&x)
+            .iter()
+            .max_by_key(|u| p.intersection_len(&self.adj[*u]))
+        else {
+            f(r);
+            return;
+        };
+        for n in p.difference(&self.adj[pivot]).iter() {
+            r.push(n);
+            self.expand(
+                r,
+                p.intersection(&self.adj[n]),
+                x.intersection(&self.adj[n]),
+                f,
+            );
+            r.pop();
+            p.remove(n);
+            x.insert(n);
+        }
+    }
+
+    // Calls f with the first k - 1 nodes of the k-cliques with any or all
+    // nodes kept, and the nodes completing them. With k = 1 that's no nodes
+    // and the kept ones.
+    fn for_each_k_prefix<F>(&self, k: usize, keep: impl Fn(usize) -> bool, mode: Mode, mut f: F)
+    where
+        F: FnMut(&[usize], &BitSet),
+    {
+        assert!(k > 0, "cliques have at least one node");
+        // Every clique is grown from its lowest selected node, so the selected
+        // nodes before it aren't candidates any more. With Mode::All the
+        // others never are.
+        let mut selected = BitSet::new(self.len());
+        for n in (0..self.len()).filter(|n| keep(*n)) {
+            selected.insert(n);
+        }
+        if k == 1 {
+            return f(&[], &selected);
+        }
+        let mut rest = match mode {
+            Mode::Any => BitSet::full(self.len()),
+            Mode::All => selected.clone(),
+        };
+        let mut clique = Vec::new();
+        for n in selected.iter() {
+            rest.remove(n);
+            clique.push(n);
+            self.grow(&mut clique, rest.intersection(&self.adj[n]), k, &mut f);
+            clique.pop();
+        }
+    }
+
+    fn grow<F>(&self, clique: &mut Vec<usize>, mut cand: BitSet, k: usize, f: &mut F)
+    where
+        F: FnMut(&[usize], &BitSet),
+    {
+        if clique.len() + 1 == k {
+            return f(clique, &cand);
+        }
+        for n in cand.clone().iter() {
+            cand.remove(n);
+            clique.push(n);
+            self.grow(clique, cand.intersection(&self.adj[n]), k, f);
+            clique.pop();
+        }
+    }
+
+    // Number of cliques of exactly k nodes, of which any or all are kept.
+    pub fn count_k_cliques(&self, k: usize, keep: impl Fn(usize) -> bool, mode: Mode) -> usize {
+        let mut count = 0;
+        self.for_each_k_prefix(k, keep, mode, |_, last| count += last.len());
+        count
+    }
+
+    // The same cliques, each with its nodes in increasing order.
+    pub fn k_cliques(&self, k: usize, keep: impl Fn(usize) -> bool, mode: Mode) -> Vec<Vec<usize>> {
+        let mut res = Vec::new();
+        self.for_each_k_prefix(k, keep, mode, |prefix, last| {
+            for n in last.iter() {
+                let mut clique = prefix.to_vec();
+                clique.push(n);
+                clique.sort();
+                res.push(clique);
+            }
+        });
+        res
+    }
+
+    // Every maximal clique with its nodes in increasing order.
+    pub fn maximal_cliques(&self) -> Vec<Vec<usize>> {
+        let mut res = Vec::new();
+        self.for_each_maximal(|clique| {
+            let mut clique = clique.to_vec();
+            clique.sort();
+            res.push(clique);
+        });
+        res
+    }
+
+    // One of the largest cliques, in increasing order.
+    pub fn maximum_clique(&self) -> Vec<usize> {
+        let mut best = Vec::new();
+        self.for_each_maximal(|clique| {
+            if clique.len() > best.len() {
+                best = clique.to_vec();
+            }
+        });
+        best.sort();
+        best
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn cliques() {
+        // Two triangles sharing 1-2, a square 3-4-5-6 with a diagonal 4-6
+        // and the isolated 7.
+        let pairs = [
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (1, 3),
+            (2, 3),
+            (3, 4),
+            (4, 5),
+            (5, 6),
+            (6, 3),
+            (4, 6),
+        ];
+        let mut edges = vec![Vec::new(); 8];
+        for (a, b) in pairs {
+            edges[a].push(b);
+            edges[b].push(a);
+        }
+        let graph = Graph::new(&edges);
+        let mut cliques = graph.maximal_cliques();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![
+                vec![0, 1, 2],
+                vec![1, 2, 3],
+                vec![3, 4, 6],
+                vec![4, 5, 6],
+                vec![7]
+            ]
+        );
+        assert_eq!(graph.maximum_clique().len(), 3);
+
+        assert_eq!(graph.count_k_cliques(3, |_| true, Mode::Any), 4);
+        assert_eq!(graph.count_k_cliques(2, |_| true, Mode::All), 10);
+        assert_eq!(graph.count_k_cliques(1, |n| n % 2 == 0, Mode::Any), 4);
+        assert_eq!(graph.count_k_cliques(3, |n| n < 4, Mode::All), 2);
+        assert_eq!(
+            graph.k_cliques(3, |n| n == 3, Mode::Any),
+            vec![vec![1, 2, 3], vec![3, 4, 6]]
+        );
+
+        edges[0].push(3);
+        edges[3].push(0);
+        let graph = Graph::new(&edges);
+        assert_eq!(graph.maximum_clique(), vec![0, 1, 2, 3]);
+        assert_eq!(
+            graph.k_cliques(4, |_| true, Mode::All),
+            vec![vec![0, 1, 2, 3]]
+        );
+    }
+}
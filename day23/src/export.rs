@@ -0,0 +1,67 @@
+use crate::Network;
+use itertools::Itertools;
+use std::fmt::Write;
+
+// Every connection once, the computers and connections of `clique` in red.
+pub fn dot(net: &Network, clique: &[usize]) -> String {
+    let graph = net.graph();
+    let mut out = String::new();
+    out.push_str("graph lan {\n  node [shape=circle, style=filled, fillcolor=white];\n");
+    for n in clique {
+        writeln!(out, "  \"{}\" [fillcolor=red];", net.name(*n)).unwrap();
+    }
+    for n in 0..graph.len() {
+        for m in graph.neighbours(n).iter().filter(|m| *m > n) {
+            let attrs = if clique.contains(&n) && clique.contains(&m) {
+                " [color=red, penwidth=3]"
+            } else {
+                ""
+            };
+            writeln!(out, "  \"{}\" -- \"{}\"{attrs};", net.name(n), net.name(m)).unwrap();
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+// The computers, the connections as pairs and the largest clique.
+pub fn json(net: &Network, clique: &[usize]) -> String {
+    let graph = net.graph();
+    let quoted = |n: &usize| format!("\"{}\"", net.name(*n));
+    let nodes = (0..graph.len()).map(|n| quoted(&n)).join(", ");
+    let edges = (0..graph.len())
+        .flat_map(|n| {
+            let ns = graph.neighbours(n).iter().filter(move |m| *m > n);
+            ns.map(move |m| format!("[{}, {}]", quoted(&n), quoted(&m)))
+        })
+        .join(",\n  ");
+    let clique = clique.iter().map(quoted).join(", ");
+    format!("{{\"nodes\": [{nodes}], \"edges\": [\n  {edges}\n], \"clique\": [{clique}]}}\n")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day23;
+    use aoc::solution::Solution;
+
+    #[test]
+    fn formats() {
+        let net = Day23::parse("aa-bb\nbb-cc\naa-cc\ncc-dd\n").unwrap();
+        let clique = net.graph().maximum_clique();
+        let dot = dot(&net, &clique);
+        assert!(dot.contains("  \"aa\" [fillcolor=red];\n"));
+        assert!(dot.contains("  \"aa\" -- \"bb\" [color=red, penwidth=3];\n"));
+        assert!(dot.contains("  \"cc\" -- \"dd\";\n"));
+        assert_eq!(
+            json(&net, &clique),
+            "{\"nodes\": [\"aa\", \"bb\", \"cc\", \"dd\"], \"edges\": [\n  \
+             [\"aa\", \"bb\"],\n  [\"aa\", \"cc\"],\n  [\"bb\", \"cc\"],\n  [\"cc\", \"dd\"]\n], \
+             \"clique\": [\"aa\", \"bb\", \"cc\"]}\n"
+        );
+
+        for bad in ["a\"b-cc\n", "aa-b\\\n", "aa-\n"] {
+            assert!(Day23::parse(bad).is_err(), "{bad}");
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use crate::clique::Mode;
+use crate::{report, Filter, Network};
+use itertools::Itertools;
+use regex::Regex;
+use std::cmp::Reverse;
+use std::fmt::Write;
+
+// Which sets of k connected computers to count or list.
+pub struct Query {
+    k: usize,
+    filter: Option<Filter>,
+    mode: Mode,
+    list: bool,
+}
+
+fn parse_query(k: &str, opts: &[&str]) -> Result<Query, String> {
+    let mut query = Query {
+        k: k.parse()
+            .ok()
+            .filter(|k| *k > 0)
+            .ok_or("k has to be a positive number")?,
+        filter: None,
+        mode: Mode::Any,
+        list: false,
+    };
+    let mut opts = opts.iter();
+    while let Some(opt) = opts.next() {
+        let filter = match *opt {
+            "--all" => {
+                query.mode = Mode::All;
+                continue;
+            }
+            "--list" => {
+                query.list = true;
+                continue;
+            }
+            "--prefix" | "--regex" | "--nodes" => {
+                let arg = opts.next().ok_or(format!("{opt} needs an argument"))?;
+                match *opt {
+                    "--prefix" => Filter::Prefix(arg.to_string()),
+                    "--regex" => Filter::Regex(Regex::new(arg).map_err(|e| e.to_string())?),
+                    _ => Filter::Nodes(arg.split(',').map(String::from).collect()),
+                }
+            }
+            _ => return Err(format!("unknown option {opt}")),
+        };
+        if query.filter.replace(filter).is_some() {
+            return Err("only one of --prefix, --regex and --nodes".to_string());
+        }
+    }
+    Ok(query)
+}
+
+fn cliques(net: &Network, q: &Query) -> String {
+    if q.list {
+        net.cliques(q.k, q.filter.as_ref(), q.mode)
+            .iter()
+            .map(|clique| format!("{}\n", clique.join(",")))
+            .collect()
+    } else {
+        format!("{}\n", net.count_cliques(q.k, q.filter.as_ref(), q.mode))
+    }
+}
+
+// One line per component, largest first.
+fn components(net: &Network) -> String {
+    let mut out = String::new();
+    for c in report::components(net.graph()) {
+        let names = c.iter().map(|n| net.name(*n)).join(",");
+        writeln!(out, "{:>5}: {names}", c.len()).unwrap();
+    }
+    out
+}
+
+fn degrees(net: &Network) -> String {
+    let mut out = String::from("degree  computers\n");
+    for (degree, count) in report::degrees(net.graph()) {
+        writeln!(out, "{degree:>6}  {count:>9}").unwrap();
+    }
+    out
+}
+
+// The computers in the most triangles first.
+fn triangles(net: &Network) -> String {
+    let triangles = report::triangles(net.graph());
+    let mut out = String::new();
+    for n in (0..triangles.len()).sorted_by_key(|n| (Reverse(triangles[*n]), net.name(*n))) {
+        writeln!(out, "{} {}", net.name(n), triangles[n]).unwrap();
+    }
+    out
+}
+
+// What the runner's lan command does, see USAGE.
+pub enum Command {
+    Cliques(Query),
+    Components,
+    Degrees,
+    Triangles,
+}
+
+pub const USAGE: &[&str] = &[
+    "cliques <k> [--prefix P|--regex RE|--nodes a,b,..] [--all] [--list]",
+    "components|degrees|triangles",
+];
+
+impl Command {
+    pub fn parse(args: &[&str]) -> Result<Command, String> {
+        match args {
+            ["cliques", k, opts @ ..] => Ok(Command::Cliques(parse_query(k, opts)?)),
+            ["components"] => Ok(Command::Components),
+            ["degrees"] => Ok(Command::Degrees),
+            ["triangles"] => Ok(Command::Triangles),
+            _ => Err(format!("unknown command {}", args.join(" "))),
+        }
+    }
+
+    pub fn run(&self, net: &Network) -> String {
+        match self {
+            Command::Cliques(q) => cliques(net, q),
+            Command::Components => components(net),
+            Command::Degrees => degrees(net),
+            Command::Triangles => triangles(net),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day23;
+    use aoc::solution::Solution;
+
+    #[test]
+    fn commands() {
+        let net = Day23::parse(include_str!("../../examples/day23.txt")).unwrap();
+        let run = |args: &[&str]| Command::parse(args).map(|c| c.run(&net));
+        assert_eq!(
+            run(&["cliques", "3", "--prefix", "t"]),
+            Ok("7\n".to_string())
+        );
+        assert_eq!(
+            run(&["cliques", "4", "--nodes", "co,de", "--list"]),
+            Ok("co,de,ka,ta\n".to_string())
+        );
+        assert_eq!(
+            run(&["cliques", "2", "--nodes", "co,de", "--all"]),
+            Ok("1\n".to_string())
+        );
+        assert_eq!(
+            run(&["cliques", "3", "--prefix", "t", "--regex", "t."]),
+            Err("only one of --prefix, --regex and --nodes".to_string())
+        );
+        assert!(run(&["degrees"])
+            .unwrap()
+            .starts_with("degree  computers\n"));
+        assert!(run(&["cliques"]).is_err());
+    }
+}
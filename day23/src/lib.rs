@@ -0,0 +1,196 @@
+pub mod clique;
+pub mod export;
+pub mod lan;
+pub mod report;
+
+use aoc::parse::{self, ParseError};
+use aoc::solution::{Answer, Export, ParseResult, Solution};
+use clique::{Graph, Mode};
+use itertools::Itertools;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+// Calls f for every clique of at least 3 nodes containing start, growing
+// them up to max_size nodes.
+fn cliques<F>(
+    edges: &[Vec<usize>],
+    edges_set: &HashSet<(usize, usize)>,
+    start: usize,
+    max_size: usize,
+    mut f: F,
+) where
+    F: FnMut(&[usize]),
+{
+    let mut node = vec![start];
+    let mut edge = vec![0];
+    'outer: while !node.is_empty() {
+        let i = node.len() - 1;
+        let n = node[i];
+        for (j, &m) in edges[n].iter().enumerate().skip(edge[i]) {
+            if m < n {
+                continue;
+            }
+            if node[0..node.len() - 1]
+                .iter()
+                .all(|prev_n| edges_set.contains(&(m, *prev_n)))
+            {
+                node.push(m);
+                edge.push(0);
+                edge[i] = j + 1;
+                if node.len() > 2 {
+                    f(node.as_slice());
+                }
+                if node.len() == max_size {
+                    break;
+                } else {
+                    continue 'outer;
+                }
+            }
+        }
+        edge.pop();
+        node.pop();
+    }
+}
+
+// The computers a clique is filtered on, by name.
+pub enum Filter {
+    Prefix(String),
+    Regex(Regex),
+    Nodes(HashSet<String>),
+}
+
+impl Filter {
+    pub fn matches(&self, name: &str) -> bool {
+        match self {
+            Filter::Prefix(p) => name.starts_with(p.as_str()),
+            Filter::Regex(re) => re.is_match(name),
+            Filter::Nodes(nodes) => nodes.contains(name),
+        }
+    }
+}
+
+pub struct Network {
+    nodes_str: Vec<String>,
+    edges: Vec<Vec<usize>>,
+    edges_set: HashSet<(usize, usize)>,
+    graph: Graph,
+}
+
+impl Network {
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    pub fn name(&self, n: usize) -> &str {
+        &self.nodes_str[n]
+    }
+
+    // Number of sets of k connected computers with any or all of them
+    // matching `filter`, or all of them with no filter.
+    pub fn count_cliques(&self, k: usize, filter: Option<&Filter>, mode: Mode) -> usize {
+        let keep = |n: usize| filter.is_none_or(|f| f.matches(&self.nodes_str[n]));
+        self.graph.count_k_cliques(k, keep, mode)
+    }
+
+    // The same sets by name, sorted.
+    pub fn cliques(&self, k: usize, filter: Option<&Filter>, mode: Mode) -> Vec<Vec<&str>> {
+        let keep = |n: usize| filter.is_none_or(|f| f.matches(&self.nodes_str[n]));
+        self.graph
+            .k_cliques(k, keep, mode)
+            .into_iter()
+            .map(|c| c.into_iter().map(|n| self.name(n)).sorted().collect())
+            .sorted()
+            .collect()
+    }
+
+    // The largest clique found by growing every clique from every node, what
+    // part 2 did before Bron–Kerbosch. Kept to compare against.
+    pub fn largest_by_dfs(&self) -> Vec<usize> {
+        let mut largest_clique = Vec::new();
+        for n in 0..self.nodes_str.len() {
+            cliques(&self.edges, &self.edges_set, n, usize::MAX, |clique| {
+                if clique.len() > largest_clique.len() {
+                    largest_clique = Vec::from(clique);
+                }
+            });
+        }
+        largest_clique
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    type Input = Network;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        // Names go into the DOT and JSON exports as they are.
+        let name = |s: &str| {
+            if !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric()) {
+                Ok(())
+            } else {
+                Err(ParseError::new(input, s, "<computer>"))
+            }
+        };
+        let edges_str: Vec<(&str, &str)> = input
+            .trim()
+            .split("\n")
+            .map(|l| {
+                let (a, b) = parse::split_once(input, l, "-")?;
+                name(a)?;
+                name(b)?;
+                Ok((a, b))
+            })
+            .collect::<ParseResult<_>>()?;
+        let nodes_str: Vec<&str> = edges_str
+            .iter()
+            .flat_map(|(a, b)| [*a, *b])
+            .sorted()
+            .dedup()
+            .collect();
+        let nodes_idx: HashMap<&str, usize> =
+            nodes_str.iter().enumerate().map(|(a, b)| (*b, a)).collect();
+
+        let mut edges = Vec::new();
+        let mut edges_set = HashSet::new();
+        edges.resize(nodes_idx.len(), Vec::default());
+        for (a, b) in edges_str.iter().map(|(a, b)| (nodes_idx[a], nodes_idx[b])) {
+            edges[a].push(b);
+            edges[b].push(a);
+            edges_set.insert((a, b));
+            edges_set.insert((b, a));
+        }
+
+        Ok(Network {
+            nodes_str: nodes_str.into_iter().map(String::from).collect(),
+            graph: Graph::new(&edges),
+            edges,
+            edges_set,
+        })
+    }
+
+    fn part1(net: &Self::Input) -> Answer {
+        let t = Filter::Prefix("t".to_string());
+        net.count_cliques(3, Some(&t), Mode::Any).into()
+    }
+
+    fn part2(net: &Self::Input) -> Answer {
+        net.graph
+            .maximum_clique()
+            .iter()
+            .map(|n| net.nodes_str[*n].as_str())
+            .sorted()
+            .join(",")
+            .into()
+    }
+
+    // The DOT graph highlights the largest clique.
+    fn export(net: &Self::Input, format: Export) -> Option<String> {
+        let clique = net.graph.maximum_clique();
+        Some(match format {
+            Export::Dot => export::dot(net, &clique),
+            Export::Json => export::json(net, &clique),
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use crate::clique::Graph;
+use aoc::unionfind::UnionFind;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+
+// The connected components, largest first.
+pub fn components(graph: &Graph) -> Vec<Vec<usize>> {
+    let mut uf = UnionFind::new(graph.len());
+    for n in 0..graph.len() {
+        for m in graph.neighbours(n).iter() {
+            uf.union(n, m);
+        }
+    }
+    let mut components = uf.components();
+    components.sort_by_key(|c| Reverse(c.len()));
+    components
+}
+
+// How many nodes have each degree, by degree.
+pub fn degrees(graph: &Graph) -> Vec<(usize, usize)> {
+    let mut count: BTreeMap<usize, usize> = BTreeMap::new();
+    for n in 0..graph.len() {
+        *count.entry(graph.neighbours(n).len()).or_default() += 1;
+    }
+    count.into_iter().collect()
+}
+
+// Number of triangles every node is in, each seen from both other corners.
+pub fn triangles(graph: &Graph) -> Vec<usize> {
+    (0..graph.len())
+        .map(|n| {
+            let ns = graph.neighbours(n);
+            ns.iter()
+                .map(|m| ns.intersection_len(graph.neighbours(m)))
+                .sum::<usize>()
+                / 2
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn analytics() {
+        // A triangle with a tail 2-3 and the separate edge 4-5.
+        let edges = vec![
+            vec![1, 2],
+            vec![0, 2],
+            vec![0, 1, 3],
+            vec![2],
+            vec![5],
+            vec![4],
+        ];
+        let graph = Graph::new(&edges);
+        assert_eq!(components(&graph), vec![vec![0, 1, 2, 3], vec![4, 5]]);
+        assert_eq!(degrees(&graph), vec![(1, 3), (2, 2), (3, 1)]);
+        assert_eq!(triangles(&graph), vec![1, 1, 1, 0, 0, 0]);
+    }
+}
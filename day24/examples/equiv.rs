@@ -0,0 +1,59 @@
+// Checks that the z bus of two circuits computes the same function of their
+// inputs, with --fix the outputs the adder check finds swapped in the first
+// one are swapped back before:
+// cargo run --release -p aoc2024day24 --example equiv -- input.txt reference.txt --fix
+use aoc::solution::Solution;
+use aoc2024day24::netlist::Netlist;
+use aoc2024day24::{adder, equiv, Circuit, Day24};
+use std::env;
+use std::fs;
+use std::process::ExitCode;
+
+fn load(path: &str) -> Result<Circuit, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+    Day24::parse(&text).map_err(|e| format!("{path}: {e}"))
+}
+
+fn run(a: &str, b: &str, fix: bool) -> Result<bool, String> {
+    let (mut a, b) = (load(a)?, load(b)?);
+    if fix {
+        let faults = adder::verify(a.gates()).map_err(|e| e.to_string())?;
+        for f in faults {
+            println!("swapping {} and {}", f.wires[0], f.wires[1]);
+            a.swap_outputs(&f.wires[0], &f.wires[1]);
+        }
+    }
+    let a = Netlist::new(a.gates()).map_err(|e| e.to_string())?;
+    let b = Netlist::new(b.gates()).map_err(|e| e.to_string())?;
+    match equiv::check(&a, &b, "z").map_err(|e| e.to_string())? {
+        None => {
+            println!("equivalent");
+            Ok(true)
+        }
+        Some(cex) => {
+            println!("different: {cex}");
+            Ok(false)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let (a, b, fix) = match args.as_slice() {
+        [_, a, b] => (a, b, false),
+        [_, a, b, "--fix"] => (a, b, true),
+        _ => {
+            println!("equiv <a.txt> <b.txt> [--fix]");
+            return ExitCode::FAILURE;
+        }
+    };
+    match run(a, b, fix) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,47 @@
+// Prints a generated circuit in the puzzle format, the swapped outputs that
+// part 2 should find go to stderr:
+// cargo run --release -p aoc2024day24 --example generate -- ripple 45 --swaps 4 --seed 7 > input.txt
+use aoc2024day24::generate::{generate, Kind};
+use std::env;
+use std::process::ExitCode;
+
+fn parse(args: &[&str]) -> Option<(Kind, usize, usize, u64)> {
+    let (kind, width, rest) = match args {
+        [kind, width, rest @ ..] => (*kind, width.parse().ok()?, rest),
+        _ => return None,
+    };
+    let kind = match kind {
+        "ripple" => Kind::Ripple,
+        "lookahead" => Kind::Lookahead,
+        "multiplier" => Kind::Multiplier,
+        _ => return None,
+    };
+    if !(1..64).contains(&width) {
+        return None;
+    }
+    let (mut swaps, mut seed) = (0, 0);
+    for flag in rest.chunks(2) {
+        match flag {
+            ["--swaps", n] => swaps = n.parse().ok()?,
+            ["--seed", s] => seed = s.parse().ok()?,
+            _ => return None,
+        }
+    }
+    Some((kind, width, swaps, seed))
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let Some((kind, width, swaps, seed)) = parse(&args[1..]) else {
+        println!("generate <ripple|lookahead|multiplier> <width 1-63> [--swaps N] [--seed S]");
+        return ExitCode::FAILURE;
+    };
+    let g = generate(kind, width, swaps, seed);
+    print!("{}", g.text);
+    if g.swaps.len() < swaps {
+        eprintln!("only {} swaps fit", g.swaps.len());
+    }
+    eprintln!("{}", g.answer());
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,354 @@
+use crate::{GateDesc, Op};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+// Checks a ripple carry adder z = x + y gate by gate against the full adder
+// of every bit:
+//
+//   h = x XOR y, g = x AND y, z = h XOR c, t = h AND c, c' = g OR t
+//
+// with bit 0 being a half adder (z = h, c' = g) and the last carry being the
+// top bit of z. Wrong gate outputs are swapped back as soon as they are
+// found, so the rest of the check sees a fixed adder. A gate with both inputs
+// wrong can't be told apart from the others and fails the check.
+
+// Two gates with swapped outputs.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Fault {
+    pub bit: usize,
+    pub wires: [String; 2],
+    pub reason: String,
+}
+
+impl Display for Fault {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b] = &self.wires;
+        write!(f, "bit {}: {a} <-> {b}: {}", self.bit, self.reason)
+    }
+}
+
+// The circuit doesn't have the shape of a ripple carry adder, not even with
+// swapped outputs.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct NotAnAdder {
+    pub bit: usize,
+    pub reason: String,
+}
+
+impl Display for NotAnAdder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "bit {}: {}", self.bit, self.reason)
+    }
+}
+
+// The ops and inputs of the gates, which swapping outputs doesn't change.
+struct Gates<'a> {
+    ops: Vec<Op>,
+    ins: Vec<&'a [String]>,
+    // Gates by their op and one of their inputs.
+    by_in: HashMap<(Op, &'a str), Vec<usize>>,
+}
+
+impl<'a> Gates<'a> {
+    fn new(descs: &'a [GateDesc]) -> Gates<'a> {
+        let mut by_in: HashMap<(Op, &str), Vec<usize>> = HashMap::new();
+        for (i, d) in descs.iter().enumerate() {
+            for input in d.ins.iter() {
+                by_in.entry((d.op, input.as_str())).or_default().push(i);
+            }
+        }
+        Gates {
+            ops: descs.iter().map(|d| d.op).collect(),
+            ins: descs.iter().map(|d| d.ins.as_slice()).collect(),
+            by_in,
+        }
+    }
+
+    fn find(&self, op: Op, a: &str, b: &str) -> Option<usize> {
+        let gates = self.by_in.get(&(op, a))?;
+        gates.iter().copied().find(|g| match self.ins[*g] {
+            [i, j] => (i.as_str(), j.as_str()) == (a, b) || (j.as_str(), i.as_str()) == (a, b),
+            _ => false,
+        })
+    }
+
+    // The only `op` gate reading `a`, with its other input.
+    fn with_input(&self, op: Op, a: &str) -> Option<(usize, &'a str)> {
+        match self.by_in.get(&(op, a))?.as_slice() {
+            [g] => match self.ins[*g] {
+                [i, j] => Some((*g, if i == a { j.as_str() } else { i.as_str() })),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+// The outputs as fixed so far, a copy for every explanation of the faults
+// still being considered.
+#[derive(Clone)]
+struct Wiring<'a> {
+    outs: Vec<&'a str>,
+    by_out: HashMap<&'a str, usize>,
+    bit: usize,
+    faults: Vec<Fault>,
+}
+
+impl<'a> Wiring<'a> {
+    fn new(descs: &'a [GateDesc]) -> Wiring<'a> {
+        let outs: Vec<&str> = descs.iter().map(|d| d.out.as_str()).collect();
+        let by_out = outs.iter().enumerate().map(|(i, o)| (*o, i)).collect();
+        Wiring {
+            outs,
+            by_out,
+            bit: 0,
+            faults: Vec::new(),
+        }
+    }
+
+    fn fail<T>(&self, reason: String) -> Result<T, NotAnAdder> {
+        Err(NotAnAdder {
+            bit: self.bit,
+            reason,
+        })
+    }
+
+    fn out(&self, gate: usize) -> &'a str {
+        self.outs[gate]
+    }
+
+    fn driver(&self, wire: &str) -> Result<usize, NotAnAdder> {
+        match self.by_out.get(wire) {
+            Some(g) => Ok(*g),
+            None => self.fail(format!("{wire} is not driven by a gate")),
+        }
+    }
+
+    fn expect(&self, gates: &Gates, op: Op, a: &str, b: &str) -> Result<usize, NotAnAdder> {
+        match gates.find(op, a, b) {
+            Some(g) => Ok(g),
+            None => self.fail(format!("no {a} {op:?} {b} gate")),
+        }
+    }
+
+    fn swap(&mut self, a: usize, b: usize, reason: String) {
+        let (wa, wb) = (self.outs[a], self.outs[b]);
+        self.outs[a] = wb;
+        self.outs[b] = wa;
+        self.by_out.insert(wa, b);
+        self.by_out.insert(wb, a);
+        self.faults.push(Fault {
+            bit: self.bit,
+            wires: [wa.to_string(), wb.to_string()],
+            reason,
+        });
+    }
+
+    // Finds the `op` gate combining the outputs of `a` and `b`. If there is
+    // none, the gate reading one of them shows what the other should be. That
+    // can go both ways, so there are up to two fixed wirings.
+    fn combine(
+        self,
+        gates: &Gates,
+        op: Op,
+        [a, b]: [usize; 2],
+        role: &str,
+    ) -> Result<Vec<(Wiring<'a>, usize)>, NotAnAdder> {
+        let (wa, wb) = (self.out(a), self.out(b));
+        if let Some(g) = gates.find(op, wa, wb) {
+            return Ok(vec![(self, g)]);
+        }
+        let mut fixed = Vec::new();
+        for (keep, bad) in [(a, b), (b, a)] {
+            let (wk, wbad) = (self.out(keep), self.out(bad));
+            let Some((g, other)) = gates.with_input(op, wk) else {
+                continue;
+            };
+            let Ok(right) = self.driver(other) else {
+                continue;
+            };
+            if right == bad || right == keep {
+                continue;
+            }
+            let mut w = self.clone();
+            let reason = format!("the {role} {op:?} reads {wk} and {other}, not {wbad}");
+            w.swap(bad, right, reason);
+            fixed.push((w, g));
+        }
+        if fixed.is_empty() {
+            return self.fail(format!("no {op:?} gate reads {wa} or {wb}"));
+        }
+        Ok(fixed)
+    }
+
+    // The gate driving `wire` has to be `gate`.
+    fn drive(
+        mut self,
+        gates: &Gates,
+        gate: usize,
+        wire: &str,
+        role: &str,
+    ) -> Result<Self, NotAnAdder> {
+        if self.out(gate) != wire {
+            let driver = self.driver(wire)?;
+            let op = gates.ops[driver];
+            let reason = format!(
+                "{wire} is driven by an {op:?}, the {role} goes to {}",
+                self.out(gate)
+            );
+            self.swap(gate, driver, reason);
+        }
+        Ok(self)
+    }
+
+    // One full adder, all the ways it can be fixed with the carry out.
+    fn full_adder(
+        self,
+        gates: &Gates,
+        [h, g]: [usize; 2],
+        carry: usize,
+    ) -> Result<Vec<(Wiring<'a>, usize)>, NotAnAdder> {
+        let z = format!("z{:02}", self.bit);
+        let mut fixed = Vec::new();
+        for (w, sum) in self.combine(gates, Op::XOR, [h, carry], "sum")? {
+            let w = w.drive(gates, sum, &z, "sum")?;
+            for (w, t) in w.combine(gates, Op::AND, [h, carry], "carry")? {
+                fixed.extend(w.combine(gates, Op::OR, [g, t], "carry out")?);
+            }
+        }
+        Ok(fixed)
+    }
+}
+
+// The width of the x input bus.
+fn width(gates: &Gates) -> usize {
+    (0..)
+        .take_while(|i| {
+            gates
+                .by_in
+                .contains_key(&(Op::XOR, format!("x{i:02}").as_str()))
+        })
+        .count()
+}
+
+// Only this many explanations are followed at once, the ones with the fewest
+// swaps so far.
+const MAX_WIRINGS: usize = 16;
+
+// All the swapped outputs, in the order they were found from bit 0 up. Where
+// a wrong wire can be explained in two ways both are followed, the fewest
+// swaps that make the whole adder right win.
+pub fn verify(descs: &[GateDesc]) -> Result<Vec<Fault>, NotAnAdder> {
+    let gates = Gates::new(descs);
+    let w = Wiring::new(descs);
+    let width = width(&gates);
+    if width == 0 {
+        return w.fail("no x00 XOR y00 gate".to_string());
+    }
+
+    let (h, g) = (
+        w.expect(&gates, Op::XOR, "x00", "y00")?,
+        w.expect(&gates, Op::AND, "x00", "y00")?,
+    );
+    let mut wirings = vec![(w.drive(&gates, h, "z00", "half adder sum")?, g)];
+    for bit in 1..width {
+        let (x, y) = (format!("x{bit:02}"), format!("y{bit:02}"));
+        let mut next = Vec::new();
+        let mut error = None;
+        for (mut w, carry) in wirings {
+            w.bit = bit;
+            let hg = [
+                w.expect(&gates, Op::XOR, &x, &y)?,
+                w.expect(&gates, Op::AND, &x, &y)?,
+            ];
+            match w.full_adder(&gates, hg, carry) {
+                Ok(fixed) => next.extend(fixed),
+                Err(e) => error = error.or(Some(e)),
+            }
+        }
+        if next.is_empty() {
+            return Err(error.unwrap());
+        }
+        next.sort_by_key(|(w, _)| w.faults.len());
+        next.truncate(MAX_WIRINGS);
+        wirings = next;
+    }
+
+    let last = format!("z{width:02}");
+    let mut error = None;
+    for (mut w, carry) in wirings {
+        w.bit = width;
+        match w.drive(&gates, carry, &last, "last carry") {
+            Ok(w) => return Ok(w.faults),
+            Err(e) => error = error.or(Some(e)),
+        }
+    }
+    Err(error.unwrap())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day24;
+    use aoc::solution::Solution;
+    use itertools::Itertools;
+
+    #[test]
+    fn faults() {
+        let mut text = String::new();
+        for i in 0..8 {
+            let p = i - 1;
+            text += &format!("x{i:02} XOR y{i:02} -> h{i:02}\ny{i:02} AND x{i:02} -> g{i:02}\n");
+            if i > 0 {
+                text +=
+                    &format!("c{p:02} XOR h{i:02} -> s{i:02}\nh{i:02} AND c{p:02} -> t{i:02}\n");
+                text += &format!("t{i:02} OR g{i:02} -> c{i:02}\n");
+            }
+        }
+        let text = text
+            .replace("-> c07", "-> z08")
+            .replace("-> s", "-> z")
+            .replace("-> h00", "-> z00")
+            .replace("-> g00", "-> c00");
+        let adder = Day24::parse(&text).unwrap();
+        assert_eq!(verify(adder.gates()), Ok(vec![]));
+
+        let swapped = text
+            .replace("-> h03", "-> tmp")
+            .replace("-> g03", "-> h03")
+            .replace("-> tmp", "-> g03")
+            .replace("-> z05", "-> tmp")
+            .replace("-> c05", "-> z05")
+            .replace("-> tmp", "-> c05")
+            .replace("-> t06", "-> tmp")
+            .replace("-> g06", "-> t06")
+            .replace("-> tmp", "-> g06");
+        // t06 and g06 only feed the same OR, swapping them changes nothing.
+        let faults = verify(Day24::parse(&swapped).unwrap().gates()).unwrap();
+        let bits = faults.iter().map(|f| f.bit).collect_vec();
+        assert_eq!(bits, vec![3, 5]);
+        let wires = faults
+            .iter()
+            .flat_map(|f| f.wires.clone())
+            .sorted()
+            .collect_vec();
+        assert_eq!(wires, vec!["c05", "g03", "h03", "z05"]);
+
+        // h05 is read by an XOR like h06 is, so at first it looks as if the
+        // carry into bit 5 was wrong.
+        let crossed = text
+            .replace("-> h05", "-> tmp")
+            .replace("-> h06", "-> h05")
+            .replace("-> tmp", "-> h06");
+        let faults = verify(Day24::parse(&crossed).unwrap().gates()).unwrap();
+        let wires = faults
+            .iter()
+            .flat_map(|f| f.wires.clone())
+            .sorted()
+            .collect_vec();
+        assert_eq!(wires, vec!["h05", "h06"]);
+
+        let broken = text.replace("t04 OR", "t04 AND");
+        assert!(verify(Day24::parse(&broken).unwrap().gates()).is_err());
+    }
+}
@@ -0,0 +1,424 @@
+use crate::netlist::{bus_bit, Netlist};
+use crate::Op;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+// Up to this many inputs every combination is simulated, 64 at a time.
+const EXHAUSTIVE_INPUTS: usize = 20;
+// Even when asked for, past this the combinations take too long.
+const MAX_EXHAUSTIVE_INPUTS: usize = 28;
+// Multipliers blow up with any variable order, give up on them at some point.
+const MAX_NODES: usize = 1 << 22;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Method {
+    Exhaustive,
+    Bdd,
+}
+
+// Inputs for which an output differs, with its value in both netlists.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Counterexample {
+    pub inputs: Vec<(String, bool)>,
+    pub output: String,
+    pub values: [bool; 2],
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [a, b] = self.values.map(|v| v as u8);
+        write!(f, "{} is {a} and {b} with ", self.output)?;
+        let ones: Vec<&str> = self
+            .inputs
+            .iter()
+            .filter(|(_, v)| *v)
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if ones.is_empty() {
+            write!(f, "all inputs 0")
+        } else {
+            write!(f, "only {} set", ones.join(" "))
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum EquivError {
+    NoOutputs(String),
+    TooBig,
+    TooManyInputs(usize),
+}
+
+impl Display for EquivError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EquivError::NoOutputs(bus) => write!(f, "neither netlist has a {bus} bus"),
+            EquivError::TooBig => write!(f, "more than {MAX_NODES} BDD nodes"),
+            EquivError::TooManyInputs(n) => write!(
+                f,
+                "{n} inputs, more than {MAX_EXHAUSTIVE_INPUTS} to try all combinations of"
+            ),
+        }
+    }
+}
+
+// The inputs of both netlists by name, wires missing in one of them are free
+// inputs there and the missing output bits are 0.
+struct Pair<'a> {
+    nets: [&'a Netlist; 2],
+    inputs: Vec<String>,
+    vars: [Vec<(usize, usize)>; 2],
+    outputs: Vec<(String, [Option<usize>; 2])>,
+}
+
+impl<'a> Pair<'a> {
+    fn new(a: &'a Netlist, b: &'a Netlist, bus: &str) -> Result<Pair<'a>, EquivError> {
+        let nets = [a, b];
+        // Bit by bit, x00 y00 x01 y01 ..., keeps the BDDs of adders small.
+        let mut inputs: Vec<String> = nets
+            .iter()
+            .flat_map(|n| n.inputs().map(|w| n.name(w).to_string()))
+            .collect();
+        inputs.sort_by_key(|name| match bus_bit(name) {
+            Some((prefix, bit)) => (bit, prefix.to_string()),
+            None => (usize::MAX, name.clone()),
+        });
+        inputs.dedup();
+        let vars = nets.map(|n| {
+            inputs
+                .iter()
+                .enumerate()
+                .filter_map(|(v, name)| Some((v, n.wire(name)?)))
+                .collect()
+        });
+
+        let buses = nets.map(|n| n.bus(bus));
+        let width = buses.iter().flatten().map(|b| b.wires.len()).max();
+        let Some(width) = width else {
+            return Err(EquivError::NoOutputs(bus.to_string()));
+        };
+        let outputs = (0..width)
+            .map(|bit| {
+                let wires = buses.map(|b| b.and_then(|b| *b.wires.get(bit)?));
+                let name = match wires {
+                    [Some(w), _] => a.name(w).to_string(),
+                    [None, Some(w)] => b.name(w).to_string(),
+                    [None, None] => format!("{bus}{bit:02}"),
+                };
+                (name, wires)
+            })
+            .collect();
+        Ok(Pair {
+            nets,
+            inputs,
+            vars,
+            outputs,
+        })
+    }
+
+    fn counterexample(&self, assignment: &[bool], output: usize) -> Counterexample {
+        let (name, wires) = &self.outputs[output];
+        let values = [0, 1].map(|i| {
+            let net = self.nets[i];
+            let mut values = vec![false; net.len()];
+            for (v, w) in self.vars[i].iter() {
+                values[*w] = assignment[*v];
+            }
+            net.eval(&mut values);
+            wires[i].is_some_and(|w| values[w])
+        });
+        Counterexample {
+            inputs: self
+                .inputs
+                .iter()
+                .cloned()
+                .zip(assignment.iter().copied())
+                .collect(),
+            output: name.clone(),
+            values,
+        }
+    }
+
+    // Every input combination, the k-th bit of the lanes is combination
+    // base + k.
+    fn exhaustive(&self) -> Option<Counterexample> {
+        let n = self.inputs.len();
+        for base in (0..1u64 << n).step_by(64) {
+            let lane = |v: usize| (0..64).fold(0, |acc, k| acc | ((base + k) >> v & 1) << k);
+            let outs = [0, 1].map(|i| {
+                let net = self.nets[i];
+                let mut values = vec![0; net.len()];
+                for (v, w) in self.vars[i].iter() {
+                    values[*w] = lane(*v);
+                }
+                net.eval_lanes(&mut values);
+                let outs: Vec<u64> = self
+                    .outputs
+                    .iter()
+                    .map(|(_, wires)| wires[i].map_or(0, |w| values[w]))
+                    .collect();
+                outs
+            });
+            for (o, (a, b)) in outs[0].iter().zip(outs[1].iter()).enumerate() {
+                if a != b {
+                    let combination = base + (a ^ b).trailing_zeros() as u64;
+                    let assignment: Vec<bool> = (0..n).map(|v| combination >> v & 1 == 1).collect();
+                    return Some(self.counterexample(&assignment, o));
+                }
+            }
+        }
+        None
+    }
+
+    fn bdd(&self) -> Result<Option<Counterexample>, EquivError> {
+        let mut bdd = Bdd::new();
+        let mut outs = [Vec::new(), Vec::new()];
+        for (i, out) in outs.iter_mut().enumerate() {
+            let net = self.nets[i];
+            let mut nodes = vec![FALSE; net.len()];
+            for (v, w) in self.vars[i].iter() {
+                nodes[*w] = bdd.var(*v as u32)?;
+            }
+            for (w, g) in net.gates() {
+                let (op, start, invert) = match g.op {
+                    Op::AND => (Bin::And, TRUE, false),
+                    Op::OR => (Bin::Or, FALSE, false),
+                    Op::XOR => (Bin::Xor, FALSE, false),
+                    Op::NAND => (Bin::And, TRUE, true),
+                    Op::NOR => (Bin::Or, FALSE, true),
+                    Op::XNOR | Op::NOT => (Bin::Xor, FALSE, true),
+                    Op::ZERO => (Bin::Or, FALSE, false),
+                    Op::ONE => (Bin::Or, TRUE, false),
+                };
+                let mut node = start;
+                for input in g.ins.iter() {
+                    node = bdd.apply(op, node, nodes[*input])?;
+                }
+                if invert {
+                    node = bdd.apply(Bin::Xor, node, TRUE)?;
+                }
+                nodes[w] = node;
+            }
+            *out = self
+                .outputs
+                .iter()
+                .map(|(_, wires)| wires[i].map_or(FALSE, |w| nodes[w]))
+                .collect();
+        }
+        for (o, (a, b)) in outs[0].iter().zip(outs[1].iter()).enumerate() {
+            if a != b {
+                let diff = bdd.apply(Bin::Xor, *a, *b)?;
+                let assignment = bdd.satisfy(diff, self.inputs.len());
+                return Ok(Some(self.counterexample(&assignment, o)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+const FALSE: u32 = 0;
+const TRUE: u32 = 1;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum Bin {
+    And,
+    Or,
+    Xor,
+}
+
+// Reduced ordered BDD, equal functions are the same node. Variable u32::MAX
+// marks the two terminals, so they come after every variable.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+struct Node {
+    var: u32,
+    lo: u32,
+    hi: u32,
+}
+
+struct Bdd {
+    nodes: Vec<Node>,
+    unique: HashMap<Node, u32>,
+    cache: HashMap<(Bin, u32, u32), u32>,
+}
+
+impl Bdd {
+    fn new() -> Bdd {
+        let terminal = |v| Node {
+            var: u32::MAX,
+            lo: v,
+            hi: v,
+        };
+        Bdd {
+            nodes: vec![terminal(FALSE), terminal(TRUE)],
+            unique: HashMap::new(),
+            cache: HashMap::new(),
+        }
+    }
+
+    fn node(&mut self, var: u32, lo: u32, hi: u32) -> Result<u32, EquivError> {
+        if lo == hi {
+            return Ok(lo);
+        }
+        let node = Node { var, lo, hi };
+        if let Some(n) = self.unique.get(&node) {
+            return Ok(*n);
+        }
+        if self.nodes.len() >= MAX_NODES {
+            return Err(EquivError::TooBig);
+        }
+        let n = self.nodes.len() as u32;
+        self.nodes.push(node);
+        self.unique.insert(node, n);
+        Ok(n)
+    }
+
+    fn var(&mut self, var: u32) -> Result<u32, EquivError> {
+        self.node(var, FALSE, TRUE)
+    }
+
+    fn apply(&mut self, op: Bin, a: u32, b: u32) -> Result<u32, EquivError> {
+        let (a, b) = (a.min(b), a.max(b));
+        match (op, a, b) {
+            (Bin::And, FALSE, _) | (Bin::Or, TRUE, _) => return Ok(a),
+            (Bin::And, TRUE, _) | (Bin::Or, FALSE, _) | (Bin::Xor, FALSE, _) => return Ok(b),
+            (Bin::And | Bin::Or, _, _) if a == b => return Ok(a),
+            (Bin::Xor, _, _) if a == b => return Ok(FALSE),
+            _ => {}
+        }
+        if let Some(n) = self.cache.get(&(op, a, b)) {
+            return Ok(*n);
+        }
+        let (na, nb) = (self.nodes[a as usize], self.nodes[b as usize]);
+        let var = na.var.min(nb.var);
+        let split = |n: Node, id: u32| if n.var == var { (n.lo, n.hi) } else { (id, id) };
+        let ((alo, ahi), (blo, bhi)) = (split(na, a), split(nb, b));
+        let lo = self.apply(op, alo, blo)?;
+        let hi = self.apply(op, ahi, bhi)?;
+        let n = self.node(var, lo, hi)?;
+        self.cache.insert((op, a, b), n);
+        Ok(n)
+    }
+
+    // Values of the variables making `node` true, all the ones it doesn't
+    // depend on are false. Only FALSE itself has no path to TRUE.
+    fn satisfy(&self, mut node: u32, vars: usize) -> Vec<bool> {
+        let mut assignment = vec![false; vars];
+        while node > TRUE {
+            let n = self.nodes[node as usize];
+            if n.lo != FALSE {
+                node = n.lo;
+            } else {
+                assignment[n.var as usize] = true;
+                node = n.hi;
+            }
+        }
+        assignment
+    }
+}
+
+// Whether the `outputs` bus is the same function of the inputs in both, None
+// if it is, otherwise an input where it isn't.
+pub fn check_with(
+    a: &Netlist,
+    b: &Netlist,
+    outputs: &str,
+    method: Method,
+) -> Result<Option<Counterexample>, EquivError> {
+    let pair = Pair::new(a, b, outputs)?;
+    match method {
+        Method::Exhaustive if pair.inputs.len() > MAX_EXHAUSTIVE_INPUTS => {
+            Err(EquivError::TooManyInputs(pair.inputs.len()))
+        }
+        Method::Exhaustive => Ok(pair.exhaustive()),
+        Method::Bdd => pair.bdd(),
+    }
+}
+
+// Exhaustive for a few inputs, a BDD for more.
+pub fn check(
+    a: &Netlist,
+    b: &Netlist,
+    outputs: &str,
+) -> Result<Option<Counterexample>, EquivError> {
+    let pair = Pair::new(a, b, outputs)?;
+    if pair.inputs.len() <= EXHAUSTIVE_INPUTS {
+        Ok(pair.exhaustive())
+    } else {
+        pair.bdd()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day24;
+    use aoc::solution::Solution;
+
+    fn netlist(text: &str) -> Netlist {
+        Netlist::new(&Day24::parse(text).unwrap().gates).unwrap()
+    }
+
+    // x + y as a ripple carry adder, and with the carry as a majority of NANDs
+    // and the sum as one 3 input XOR.
+    fn adders(width: usize) -> (String, String) {
+        let mut ripple = String::from("x00 XOR y00 -> z00\nx00 AND y00 -> c00\n");
+        let mut nands = String::from("x00 XOR y00 -> z00\nx00 AND y00 -> c00\n");
+        for i in 1..width {
+            let p = i - 1;
+            let c = if i == width - 1 {
+                format!("z{width:02}")
+            } else {
+                format!("c{i:02}")
+            };
+            ripple += &format!("x{i:02} XOR y{i:02} -> h{i:02}\nx{i:02} AND y{i:02} -> g{i:02}\n");
+            ripple += &format!("h{i:02} XOR c{p:02} -> z{i:02}\nh{i:02} AND c{p:02} -> t{i:02}\n");
+            ripple += &format!("g{i:02} OR t{i:02} -> {c}\n");
+            nands += &format!("x{i:02} XOR y{i:02} XOR c{p:02} -> z{i:02}\n");
+            nands += &format!("x{i:02} NAND y{i:02} -> a{i:02}\nx{i:02} NAND c{p:02} -> b{i:02}\n");
+            nands += &format!("y{i:02} NAND c{p:02} -> d{i:02}\n");
+            nands += &format!("a{i:02} NAND b{i:02} NAND d{i:02} -> {c}\n");
+        }
+        (ripple, nands)
+    }
+
+    #[test]
+    fn equivalence() {
+        for (width, method) in [(6, Method::Exhaustive), (6, Method::Bdd), (40, Method::Bdd)] {
+            let (ripple, nands) = adders(width);
+            let (ripple, wrong) = (
+                netlist(&ripple),
+                netlist(&nands.replace("-> z03", "-> z04x")),
+            );
+            let nands = netlist(&nands);
+            assert_eq!(check_with(&ripple, &nands, "z", method), Ok(None));
+
+            // z03 is missing, so 0, in the wrong one.
+            let cex = check_with(&ripple, &wrong, "z", method).unwrap().unwrap();
+            assert_eq!(cex.output, "z03");
+            assert_eq!(cex.values, [true, false]);
+        }
+
+        let (ripple, _) = adders(30);
+        let swapped = ripple
+            .replace("-> g12", "-> tmp")
+            .replace("-> z12", "-> g12")
+            .replace("-> tmp", "-> z12");
+        let (ripple, swapped) = (netlist(&ripple), netlist(&swapped));
+        let cex = check(&ripple, &swapped, "z").unwrap().unwrap();
+        assert_eq!(cex.output, "z12");
+        let mut values = vec![false; swapped.len()];
+        for (name, v) in cex.inputs.iter() {
+            values[swapped.wire(name).unwrap()] = *v;
+        }
+        swapped.eval(&mut values);
+        assert_eq!(values[swapped.wire("z12").unwrap()], cex.values[1]);
+        assert_eq!(
+            check(&ripple, &ripple, "q"),
+            Err(EquivError::NoOutputs("q".to_string()))
+        );
+        assert_eq!(
+            check_with(&ripple, &ripple, "z", Method::Exhaustive),
+            Err(EquivError::TooManyInputs(60))
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::netlist::Netlist;
+use crate::Op;
+use std::fmt::Write;
+
+fn color(op: Op) -> &'static str {
+    match op {
+        Op::AND => "lightblue",
+        Op::OR => "palegreen",
+        Op::XOR => "gold",
+        Op::NAND => "lightskyblue",
+        Op::NOR => "lightgreen",
+        Op::XNOR => "orange",
+        Op::NOT => "pink",
+        Op::ZERO | Op::ONE => "lightgrey",
+    }
+}
+
+// One node per wire, labelled with the gate driving it. Buses nobody drives
+// are ranked first, buses nobody reads last, and the `bad` wires are drawn
+// red.
+pub fn dot(netlist: &Netlist, bad: &[usize]) -> String {
+    let mut read = vec![false; netlist.len()];
+    for w in 0..netlist.len() {
+        if let Some(g) = netlist.driver(w) {
+            for i in g.ins.iter() {
+                read[*i] = true;
+            }
+        }
+    }
+
+    let mut out = String::new();
+    out.push_str("digraph circuit {\n  rankdir=LR;\n  node [shape=box, style=filled];\n");
+    for w in 0..netlist.len() {
+        let name = netlist.name(w);
+        let mut attrs = match netlist.driver(w) {
+            Some(g) => format!("label=\"{:?}\\n{name}\", fillcolor={}", g.op, color(g.op)),
+            None => "shape=ellipse, fillcolor=white".to_string(),
+        };
+        if bad.contains(&w) {
+            attrs.push_str(", color=red, penwidth=3");
+        }
+        writeln!(out, "  \"{name}\" [{attrs}];").unwrap();
+    }
+    for w in 0..netlist.len() {
+        if let Some(g) = netlist.driver(w) {
+            for i in g.ins.iter() {
+                writeln!(
+                    out,
+                    "  \"{}\" -> \"{}\";",
+                    netlist.name(*i),
+                    netlist.name(w)
+                )
+                .unwrap();
+            }
+        }
+    }
+    for bus in netlist.buses() {
+        let wires: Vec<usize> = bus.wires.iter().flatten().copied().collect();
+        let rank = if wires.iter().all(|w| netlist.driver(*w).is_none()) {
+            "source"
+        } else if wires.iter().all(|w| !read[*w]) {
+            "sink"
+        } else {
+            "same"
+        };
+        let names: Vec<String> = wires
+            .iter()
+            .map(|w| format!("\"{}\"", netlist.name(*w)))
+            .collect();
+        writeln!(out, "  {{ rank={rank}; {}; }}", names.join("; ")).unwrap();
+    }
+    out.push_str("}\n");
+    out
+}
+
+// The gates by the wire they drive and the buses with null for missing bits.
+pub fn json(netlist: &Netlist) -> String {
+    let gates: Vec<String> = (0..netlist.len())
+        .filter_map(|w| {
+            let g = netlist.driver(w)?;
+            let ins: Vec<String> = g
+                .ins
+                .iter()
+                .map(|i| format!("\"{}\"", netlist.name(*i)))
+                .collect();
+            Some(format!(
+                r#"{{"out": "{}", "op": "{:?}", "ins": [{}]}}"#,
+                netlist.name(w),
+                g.op,
+                ins.join(", ")
+            ))
+        })
+        .collect();
+    let buses: Vec<String> = netlist
+        .buses()
+        .map(|bus| {
+            let wires: Vec<String> = bus
+                .wires
+                .iter()
+                .map(|w| match w {
+                    Some(w) => format!("\"{}\"", netlist.name(*w)),
+                    None => "null".to_string(),
+                })
+                .collect();
+            format!("\"{}\": [{}]", bus.name, wires.join(", "))
+        })
+        .collect();
+    format!(
+        "{{\"gates\": [\n  {}\n], \"buses\": {{\n  {}\n}}}}\n",
+        gates.join(",\n  "),
+        buses.join(",\n  ")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day24;
+    use aoc::solution::Solution;
+
+    #[test]
+    fn formats() {
+        let half = Day24::parse("x00 XOR y00 -> z00\nx00 AND y00 -> z01\n").unwrap();
+        let netlist = Netlist::new(&half.gates).unwrap();
+        assert_eq!(
+            json(&netlist),
+            "{\"gates\": [\n  \
+             {\"out\": \"z00\", \"op\": \"XOR\", \"ins\": [\"x00\", \"y00\"]},\n  \
+             {\"out\": \"z01\", \"op\": \"AND\", \"ins\": [\"x00\", \"y00\"]}\n], \
+             \"buses\": {\n  \"x\": [\"x00\"],\n  \"y\": [\"y00\"],\n  \"z\": [\"z00\", \"z01\"]\n}}\n"
+        );
+
+        let dot = dot(&netlist, &[netlist.wire("z01").unwrap()]);
+        assert!(dot.contains("  \"z00\" [label=\"XOR\\nz00\", fillcolor=gold];\n"));
+        assert!(dot.contains("fillcolor=lightblue, color=red, penwidth=3];\n"));
+        assert!(dot.contains("  \"x00\" -> \"z01\";\n"));
+        assert!(dot.contains("  { rank=sink; \"z00\"; \"z01\"; }\n"));
+    }
+}
@@ -0,0 +1,361 @@
+use crate::netlist::Netlist;
+use crate::{GateDesc, Op, SplitMix};
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Kind {
+    // z = x + y, one full adder per bit like the puzzle input.
+    Ripple,
+    // z = x + y, carries computed ahead within blocks of 4 bits.
+    Lookahead,
+    // z = x * y, an array of partial products summed by ripple adders.
+    Multiplier,
+}
+
+// A circuit in the puzzle format, with the outputs swapped into it.
+pub struct Generated {
+    pub text: String,
+    pub x: u64,
+    pub y: u64,
+    pub swaps: Vec<[String; 2]>,
+}
+
+impl Generated {
+    // What part 2 should answer.
+    pub fn answer(&self) -> String {
+        let mut wires: Vec<&str> = self.swaps.iter().flatten().map(|w| w.as_str()).collect();
+        wires.sort();
+        wires.join(",")
+    }
+}
+
+struct Builder {
+    rng: SplitMix,
+    used: HashSet<String>,
+    // Length of the new names and how many of that length are used.
+    name_len: u32,
+    taken: usize,
+    gates: Vec<GateDesc>,
+}
+
+impl Builder {
+    // Random three letter names like the puzzle's, never looking like a bus.
+    // Once half of them are used the names get a letter longer, so there's
+    // always a free one to hit.
+    fn name(&mut self) -> String {
+        const LETTERS: &[u8] = b"abcdefghijklmnopqrstuvw";
+        if 2 * self.taken >= LETTERS.len().pow(self.name_len) {
+            self.name_len += 1;
+            self.taken = 0;
+        }
+        loop {
+            let name: String = (0..self.name_len)
+                .map(|_| LETTERS[self.rng.below(LETTERS.len())] as char)
+                .collect();
+            if self.used.insert(name.clone()) {
+                self.taken += 1;
+                return name;
+            }
+        }
+    }
+
+    fn gate(&mut self, op: Op, a: &str, b: &str) -> String {
+        let out = self.name();
+        self.gates.push(GateDesc {
+            ins: vec![a.to_string(), b.to_string()],
+            op,
+            out: out.clone(),
+        });
+        out
+    }
+
+    // A chain of binary gates.
+    fn all(&mut self, op: Op, ins: &[String]) -> String {
+        let mut out = ins[0].clone();
+        for i in ins[1..].iter() {
+            out = self.gate(op, &out, i);
+        }
+        out
+    }
+
+    fn half_adder(&mut self, a: &str, b: &str) -> (String, String) {
+        (self.gate(Op::XOR, a, b), self.gate(Op::AND, a, b))
+    }
+
+    fn full_adder(&mut self, a: &str, b: &str, c: &str) -> (String, String) {
+        let (h, g) = self.half_adder(a, b);
+        let (sum, t) = self.half_adder(&h, c);
+        (sum, self.gate(Op::OR, &g, &t))
+    }
+
+    // a + b with `a` at most as wide as `b`, one bit wider than `b`.
+    fn add(&mut self, a: &[String], b: &[String]) -> Vec<String> {
+        let mut sums = Vec::new();
+        let mut carry: Option<String> = None;
+        for (k, bk) in b.iter().enumerate() {
+            let (sum, c) = match (a.get(k), carry) {
+                (Some(ak), Some(c)) => self.full_adder(ak, bk, &c),
+                (Some(ak), None) => self.half_adder(ak, bk),
+                (None, Some(c)) => self.half_adder(bk, &c),
+                (None, None) => {
+                    sums.push(bk.clone());
+                    carry = None;
+                    continue;
+                }
+            };
+            sums.push(sum);
+            carry = Some(c);
+        }
+        sums.extend(carry);
+        sums
+    }
+
+    fn lookahead(&mut self, x: &[String], y: &[String]) -> Vec<String> {
+        let width = x.len();
+        let mut p = Vec::new();
+        let mut g = Vec::new();
+        for i in 0..width {
+            let (pi, gi) = self.half_adder(&x[i], &y[i]);
+            p.push(pi);
+            g.push(gi);
+        }
+        let mut sums = Vec::new();
+        let mut carry_in: Option<String> = None;
+        for start in (0..width).step_by(4) {
+            let end = (start + 4).min(width);
+            // carries[i - start] goes into bit i, the last one out of the block.
+            let mut carries = vec![carry_in.clone()];
+            for i in start + 1..=end {
+                // g[j] carried through all of p[j + 1..i].
+                let mut terms = Vec::new();
+                for j in (start..i).rev() {
+                    let mut ins = p[j + 1..i].to_vec();
+                    ins.push(g[j].clone());
+                    terms.push(self.all(Op::AND, &ins));
+                }
+                if let Some(c) = carry_in.as_ref() {
+                    let mut ins = p[start..i].to_vec();
+                    ins.push(c.clone());
+                    terms.push(self.all(Op::AND, &ins));
+                }
+                carries.push(Some(self.all(Op::OR, &terms)));
+            }
+            for i in start..end {
+                sums.push(match &carries[i - start] {
+                    Some(c) => self.gate(Op::XOR, &p[i], c),
+                    None => p[i].clone(),
+                });
+            }
+            carry_in = carries.pop().unwrap();
+        }
+        sums.extend(carry_in);
+        sums
+    }
+
+    fn multiplier(&mut self, x: &[String], y: &[String]) -> Vec<String> {
+        let width = x.len();
+        let row = |b: &mut Builder, j: usize| -> Vec<String> {
+            (0..width).map(|i| b.gate(Op::AND, &x[i], &y[j])).collect()
+        };
+        // Bits j - 1 and up of the product of x and y[..j].
+        let mut acc = row(self, 0);
+        let mut product = Vec::new();
+        for j in 1..width {
+            product.push(acc[0].clone());
+            let partial = row(self, j);
+            acc = self.add(&acc[1..], &partial);
+        }
+        product.extend(acc);
+        product
+    }
+
+    // Makes `wire` the output `name`.
+    fn rename(&mut self, wire: &str, name: &str) {
+        for g in self.gates.iter_mut() {
+            if g.out == wire {
+                g.out = name.to_string();
+            }
+            for i in g.ins.iter_mut() {
+                if i == wire {
+                    *i = name.to_string();
+                }
+            }
+        }
+    }
+}
+
+// Swaps the outputs of `count` random pairs of gates, as long as the circuit
+// stays acyclic. No gate reads two swapped wires: for an OR of two of them
+// swapping wouldn't change anything, and with both inputs wrong nothing shows
+// which gate was meant.
+fn inject(gates: &mut [GateDesc], count: usize, rng: &mut SplitMix) -> Vec<[String; 2]> {
+    let mut readers: HashMap<String, Vec<usize>> = HashMap::new();
+    for (i, g) in gates.iter().enumerate() {
+        for input in g.ins.iter() {
+            readers.entry(input.clone()).or_default().push(i);
+        }
+    }
+
+    let mut swapped: HashSet<usize> = HashSet::new();
+    let mut reading: HashSet<usize> = HashSet::new();
+    let mut swaps = Vec::new();
+    let mut attempts = 0;
+    while swaps.len() < count && attempts < 100 * gates.len() {
+        attempts += 1;
+        let (a, b) = (rng.below(gates.len()), rng.below(gates.len()));
+        if a == b || swapped.contains(&a) || swapped.contains(&b) {
+            continue;
+        }
+        let (wa, wb) = (gates[a].out.clone(), gates[b].out.clone());
+        let read: Vec<usize> = [&wa, &wb]
+            .iter()
+            .flat_map(|w| readers.get(*w).into_iter().flatten().copied())
+            .collect();
+        if read.iter().any(|g| reading.contains(g))
+            || (1..read.len()).any(|i| read[..i].contains(&read[i]))
+        {
+            continue;
+        }
+        gates[a].out = wb.clone();
+        gates[b].out = wa.clone();
+        if Netlist::new(gates).is_err() {
+            gates[a].out = wa;
+            gates[b].out = wb;
+            continue;
+        }
+        swapped.extend([a, b]);
+        reading.extend(read);
+        swaps.push([wa, wb]);
+    }
+    swaps
+}
+
+// A `width` bit circuit with random x and y values and `swaps` pairs of
+// swapped outputs, the same for the same seed.
+pub fn generate(kind: Kind, width: usize, swaps: usize, seed: u64) -> Generated {
+    assert!(width > 0 && width < 64, "width has to be 1 to 63");
+    let mut b = Builder {
+        rng: SplitMix(seed),
+        used: HashSet::new(),
+        name_len: 3,
+        taken: 0,
+        gates: Vec::new(),
+    };
+    let x: Vec<String> = (0..width).map(|i| format!("x{i:02}")).collect();
+    let y: Vec<String> = (0..width).map(|i| format!("y{i:02}")).collect();
+    let z = match kind {
+        Kind::Ripple => {
+            let (sum, carry) = b.half_adder(&x[0], &y[0]);
+            let mut z = vec![sum];
+            let mut carry = carry;
+            for i in 1..width {
+                let (sum, c) = b.full_adder(&x[i], &y[i], &carry);
+                z.push(sum);
+                carry = c;
+            }
+            z.push(carry);
+            z
+        }
+        Kind::Lookahead => b.lookahead(&x, &y),
+        Kind::Multiplier => b.multiplier(&x, &y),
+    };
+    for (i, wire) in z.iter().enumerate() {
+        b.rename(wire, &format!("z{i:02}"));
+    }
+    // Nothing drives the top bit of a 1 bit multiplier.
+    if z.len() < 2 * width && kind == Kind::Multiplier {
+        b.gates.push(GateDesc {
+            ins: vec![],
+            op: Op::ZERO,
+            out: format!("z{:02}", z.len()),
+        });
+    }
+
+    let mut rng = b.rng;
+    let mut gates = b.gates;
+    let swaps = inject(&mut gates, swaps, &mut rng);
+    let mask = (1 << width) - 1;
+    let (xv, yv) = (rng.next() & mask, rng.next() & mask);
+
+    let mut text = String::new();
+    for (name, v) in [("x", xv), ("y", yv)] {
+        for i in 0..width {
+            writeln!(text, "{name}{i:02}: {}", v >> i & 1).unwrap();
+        }
+    }
+    text.push('\n');
+    // In random order with the inputs either way around, like the puzzle.
+    for i in (1..gates.len()).rev() {
+        gates.swap(i, rng.below(i + 1));
+    }
+    for g in gates.iter_mut() {
+        if rng.next() & 1 == 1 {
+            g.ins.reverse();
+        }
+        match g.op {
+            Op::ZERO => writeln!(text, "0 -> {}", g.out).unwrap(),
+            op => writeln!(text, "{} {op:?} {} -> {}", g.ins[0], g.ins[1], g.out).unwrap(),
+        }
+    }
+    Generated {
+        text,
+        x: xv,
+        y: yv,
+        swaps,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::equiv;
+    use crate::Day24;
+    use aoc::solution::{Answer, Solution};
+
+    #[test]
+    fn circuits() {
+        for width in [1, 2, 5, 13] {
+            for kind in [Kind::Ripple, Kind::Lookahead, Kind::Multiplier] {
+                let g = generate(kind, width, 0, width as u64);
+                let circuit = Day24::parse(&g.text).unwrap();
+                let z = match kind {
+                    Kind::Multiplier => g.x * g.y,
+                    _ => g.x + g.y,
+                };
+                assert_eq!(Day24::part1(&circuit), z.into(), "{kind:?} {width}");
+            }
+        }
+
+        // Wider than an answer number.
+        let g = generate(Kind::Multiplier, 40, 0, 3);
+        let z = g.x as u128 * g.y as u128;
+        let circuit = Day24::parse(&g.text).unwrap();
+        assert_eq!(Day24::part1(&circuit), Answer::Str(z.to_string()));
+
+        // More gates than three letter names.
+        let g = generate(Kind::Multiplier, 50, 0, 4);
+        let circuit = Day24::parse(&g.text).unwrap();
+        assert!(circuit.gates().len() > 23 * 23 * 23);
+        let z = g.x as u128 * g.y as u128;
+        assert_eq!(Day24::part1(&circuit), Answer::Str(z.to_string()));
+
+        let ripple = generate(Kind::Ripple, 40, 0, 1);
+        let lookahead = generate(Kind::Lookahead, 40, 0, 2);
+        let [ripple, lookahead] = [ripple, lookahead]
+            .map(|g| Netlist::new(Day24::parse(&g.text).unwrap().gates()).unwrap());
+        assert_eq!(equiv::check(&ripple, &lookahead, "z"), Ok(None));
+
+        // The repair finds the same swaps as were injected.
+        for seed in 0..200 {
+            let g = generate(Kind::Ripple, 8 + seed as usize % 40, 4, seed);
+            assert_eq!(g.swaps.len(), 4);
+            let circuit = Day24::parse(&g.text).unwrap();
+            assert_eq!(
+                Day24::part2(&circuit),
+                Answer::Str(g.answer()),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -0,0 +1,816 @@
+pub mod adder;
+pub mod equiv;
+pub mod export;
+pub mod generate;
+pub mod netlist;
+
+use aoc::parse::{self, ParseError};
+use aoc::search;
+use aoc::solution::{Answer, Export, ParseResult, Solution};
+use itertools::Itertools;
+use netlist::{Netlist, NetlistError};
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+// Ids are created sequentially
+struct IdToStr {
+    id_to_name: Vec<String>,
+    name_to_id: HashMap<String, usize>,
+}
+
+impl IdToStr {
+    fn new() -> IdToStr {
+        IdToStr {
+            id_to_name: Vec::new(),
+            name_to_id: HashMap::new(),
+        }
+    }
+
+    fn get_or_create_id(&mut self, s: &str) -> usize {
+        if let Some(id) = self.name_to_id.get(s) {
+            *id
+        } else {
+            let id = self.name_to_id.len();
+            self.name_to_id.insert(s.to_string(), id);
+            self.id_to_name.push(s.to_string());
+            id
+        }
+    }
+
+    fn get_id(&self, s: &str) -> Option<usize> {
+        self.name_to_id.get(s).copied()
+    }
+
+    fn get_str(&self, id: usize) -> Option<&str> {
+        self.id_to_name.get(id).map(|x| x.as_str())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(clippy::upper_case_acronyms)]
+pub enum Op {
+    AND,
+    XOR,
+    OR,
+    NAND,
+    NOR,
+    XNOR,
+    NOT,
+    // Constant drivers, without inputs.
+    ZERO,
+    ONE,
+}
+
+impl Op {
+    // Only the ops written between their inputs.
+    fn from_str(s: &str) -> Option<Op> {
+        match s {
+            "AND" => Some(Op::AND),
+            "XOR" => Some(Op::XOR),
+            "OR" => Some(Op::OR),
+            "NAND" => Some(Op::NAND),
+            "NOR" => Some(Op::NOR),
+            "XNOR" => Some(Op::XNOR),
+            _ => None,
+        }
+    }
+
+    // Any number of inputs for the ops besides NOT and the constants, XOR is
+    // true for an odd number of true inputs.
+    #[inline(always)]
+    fn eval<I>(&self, ins: I) -> bool
+    where
+        I: IntoIterator<Item = bool>,
+    {
+        let mut ins = ins.into_iter();
+        match self {
+            Op::AND => ins.all(|v| v),
+            Op::XOR => ins.fold(false, |acc, v| acc ^ v),
+            Op::OR => ins.any(|v| v),
+            Op::NAND => !ins.all(|v| v),
+            Op::NOR => !ins.any(|v| v),
+            Op::XNOR => !ins.fold(false, |acc, v| acc ^ v),
+            Op::NOT => !ins.next().unwrap(),
+            Op::ZERO => false,
+            Op::ONE => true,
+        }
+    }
+
+    // Same as eval for every bit of the lanes at once.
+    #[inline(always)]
+    fn eval_lanes<I>(&self, ins: I) -> u64
+    where
+        I: IntoIterator<Item = u64>,
+    {
+        let mut ins = ins.into_iter();
+        match self {
+            Op::AND => ins.fold(!0, |acc, v| acc & v),
+            Op::XOR => ins.fold(0, |acc, v| acc ^ v),
+            Op::OR => ins.fold(0, |acc, v| acc | v),
+            Op::NAND => !ins.fold(!0, |acc, v| acc & v),
+            Op::NOR => !ins.fold(0, |acc, v| acc | v),
+            Op::XNOR => !ins.fold(0, |acc, v| acc ^ v),
+            Op::NOT => !ins.next().unwrap(),
+            Op::ZERO => 0,
+            Op::ONE => !0,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Gate {
+    id: usize,
+    ins: Vec<usize>,
+    op: Op,
+}
+
+pub struct GateDesc {
+    ins: Vec<String>,
+    op: Op,
+    out: String,
+}
+
+pub struct Circuit {
+    signals: Vec<(String, u8)>,
+    gates: Vec<GateDesc>,
+}
+
+impl Circuit {
+    pub fn gates(&self) -> &[GateDesc] {
+        &self.gates
+    }
+
+    // Exchanges the wires two gates drive.
+    pub fn swap_outputs(&mut self, a: &str, b: &str) {
+        for g in self.gates.iter_mut() {
+            if g.out == a {
+                g.out = b.to_string();
+            } else if g.out == b {
+                g.out = a.to_string();
+            }
+        }
+    }
+}
+
+struct Device<'a> {
+    ops: Vec<&'a Gate>,
+    bitwidth: usize,
+}
+
+impl<'a> Device<'a> {
+    fn try_new(graph: &Graph) -> Option<Device<'_>> {
+        // Topological sort of gates so evaluation later is only simple loop,
+        // constants have no inputs to wait for.
+        let constants = graph
+            .gates
+            .iter()
+            .skip(graph.bitwidth * 2)
+            .filter(|g| g.ins.is_empty());
+        let mut ops: Vec<&Gate> = constants.collect();
+        let mut queue: VecDeque<usize> = (0..graph.bitwidth * 2)
+            .chain(ops.iter().map(|g| g.id))
+            .collect();
+        let mut incomming: Vec<usize> = graph.gates.iter().map(|g| g.ins.len()).collect();
+        let mut visited = 0;
+        while let Some(gate) = queue.pop_front() {
+            visited += 1;
+            for next_gate in graph.outs[gate].iter() {
+                incomming[*next_gate] -= 1;
+                if incomming[*next_gate] == 0 {
+                    queue.push_back(*next_gate);
+                    ops.push(&graph.gates[*next_gate]);
+                }
+            }
+        }
+        (visited == graph.gates.len()).then_some(Device {
+            ops,
+            bitwidth: graph.bitwidth,
+        })
+    }
+
+    // Bit sliced, bit k of the lane of a wire is its value for the k-th of 64
+    // (x, y) pairs. Takes the lanes of the x and y bits and gives the ones of
+    // the result bits.
+    fn eval_lanes(&self, x: &[u64], y: &[u64]) -> Vec<u64> {
+        let bw = self.bitwidth;
+        let mut val = vec![0; bw * 2 + 1 + self.ops.len()];
+        val[..bw].copy_from_slice(x);
+        val[bw..bw * 2].copy_from_slice(y);
+        for g in self.ops.iter() {
+            val[g.id] = g.op.eval_lanes(g.ins.iter().map(|i| val[*i]));
+        }
+        val[bw * 2..bw * 3 + 1].to_vec()
+    }
+
+    // Results for up to 64 pairs.
+    fn eval_batch(&self, pairs: &[(u64, u64)]) -> Vec<u64> {
+        let x: Vec<u64> = pairs.iter().map(|p| p.0).collect();
+        let y: Vec<u64> = pairs.iter().map(|p| p.1).collect();
+        let z = self.eval_lanes(&to_lanes(&x, self.bitwidth), &to_lanes(&y, self.bitwidth));
+        from_lanes(&z, pairs.len())
+    }
+}
+
+// Lane i has bit i of every value, at most 64 values.
+fn to_lanes(values: &[u64], width: usize) -> Vec<u64> {
+    (0..width)
+        .map(|i| {
+            values
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (k, v)| acc | (v >> i & 1) << k)
+        })
+        .collect()
+}
+
+fn from_lanes(lanes: &[u64], n: usize) -> Vec<u64> {
+    (0..n)
+        .map(|k| {
+            lanes
+                .iter()
+                .enumerate()
+                .fold(0, |acc, (i, l)| acc | (l >> k & 1) << i)
+        })
+        .collect()
+}
+
+// Deterministic, so the answer doesn't change between runs.
+struct SplitMix(u64);
+
+impl SplitMix {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Slightly biased for huge `n`, fine for picking wires.
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+}
+
+// Random additions a set of swaps has to get right besides the single bits.
+const RANDOM_CHECKS: usize = 4096;
+
+// Random operands go straight into the lanes, 64 additions per evaluation.
+fn random_adds(dev: &Device, checks: usize) -> bool {
+    let mut rng = SplitMix(checks as u64);
+    for _ in 0..checks.div_ceil(64) {
+        let x: Vec<u64> = (0..dev.bitwidth).map(|_| rng.next()).collect();
+        let y: Vec<u64> = (0..dev.bitwidth).map(|_| rng.next()).collect();
+        let z = from_lanes(&dev.eval_lanes(&x, &y), 64);
+        let (x, y) = (from_lanes(&x, 64), from_lanes(&y, 64));
+        if (0..64).any(|k| z[k] != x[k] + y[k]) {
+            return false;
+        }
+    }
+    true
+}
+
+struct Graph {
+    bitwidth: usize,
+    nameid: IdToStr,
+    gates: Vec<Gate>,
+    outs: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    // None unless every wire besides the x and y bits has exactly one gate
+    // and the z bits are there.
+    fn from_gates(bitwidth: usize, gate_descs: &[GateDesc]) -> Option<Graph> {
+        let mut nameid = IdToStr::new();
+
+        // The exits get names no wire can have.
+        for (n, p) in [(bitwidth, "x"), (bitwidth, "y"), (bitwidth + 1, "#out")] {
+            for i in 0..n {
+                nameid.get_or_create_id(format!("{}{:02}", p, i).as_str());
+            }
+        }
+
+        let mut tmp_gates: Vec<(usize, Gate)> = gate_descs
+            .iter()
+            .map(|gd| {
+                let ins = gd.ins.iter().map(|i| nameid.get_or_create_id(i)).collect();
+                let out = nameid.get_or_create_id(&gd.out);
+                (
+                    out,
+                    Gate {
+                        id: out,
+                        ins,
+                        op: gd.op,
+                    },
+                )
+            })
+            .collect();
+
+        for i in 0..bitwidth * 2 {
+            tmp_gates.push((
+                i,
+                Gate {
+                    id: i,
+                    ins: vec![i],
+                    op: Op::AND,
+                },
+            ));
+        }
+        // virtual "exit" gates to allow for output gate swapping.
+        for i in 0..bitwidth + 1 {
+            let s = format!("z{:02}", i);
+            let in_id = nameid.get_id(s.as_str())?;
+            let id = bitwidth * 2 + i;
+            tmp_gates.push((
+                id,
+                Gate {
+                    id,
+                    ins: vec![in_id],
+                    op: Op::AND,
+                },
+            ));
+        }
+        tmp_gates.sort_unstable_by_key(|(out, _g)| *out);
+        if tmp_gates.iter().enumerate().any(|(i, (out, _))| i != *out) {
+            return None;
+        }
+        let gates: Vec<Gate> = tmp_gates.into_iter().map(|(_, g)| g).collect();
+
+        let mut outs = Vec::new();
+        outs.resize_with(gates.len(), Vec::new);
+        for (i, g) in gates.iter().enumerate().skip(bitwidth * 2) {
+            for j in g.ins.iter() {
+                outs[*j].push(i);
+            }
+        }
+
+        Some(Graph {
+            bitwidth,
+            nameid,
+            gates,
+            outs,
+        })
+    }
+
+    fn swap_gates(&mut self, a: usize, b: usize) {
+        assert!(a != b && a > self.bitwidth * 3 && b > self.bitwidth * 3);
+        let offset = 1000000000;
+        for n in [a, b] {
+            for gn in self.outs[n].iter() {
+                for input in self.gates[*gn].ins.iter_mut() {
+                    if *input == n {
+                        *input = n + offset;
+                    }
+                }
+            }
+        }
+        self.outs.swap(a, b);
+        for (ap, bp) in [(a, b), (b, a)] {
+            for gn in self.outs[ap].iter() {
+                for input in self.gates[*gn].ins.iter_mut() {
+                    if *input == bp + offset {
+                        *input = ap;
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl search::Graph for Graph {
+    type Node = usize;
+    type Cost = usize;
+
+    fn neighbours(&self, n: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.outs[n].iter().map(|m| (*m, 1))
+    }
+}
+
+// The graph with edges going from the outputs of gates to their inputs.
+struct Backwards<'a>(&'a Graph);
+
+impl search::Graph for Backwards<'_> {
+    type Node = usize;
+    type Cost = usize;
+
+    fn neighbours(&self, n: usize) -> impl Iterator<Item = (usize, usize)> {
+        self.0.gates[n].ins.iter().map(|m| (*m, 1))
+    }
+}
+
+fn get_shortest_paths(graph: &Graph, from: usize, to: usize) -> Vec<BTreeMap<Op, u8>> {
+    let paths = search::bfs(graph, from, |n| n == to);
+
+    let Some(len) = paths.end() else {
+        return vec![];
+    };
+
+    let mut res = Vec::new();
+    res.resize_with(len + 1, BTreeMap::new);
+
+    for n in paths.dag().states(&[to]) {
+        *res[paths.dist(n).unwrap()]
+            .entry(graph.gates[n].op)
+            .or_insert(0) += 1;
+    }
+
+    // frirst and last gates not needed as they are artificial ANDs
+    res.remove(0);
+    res.pop();
+    res
+}
+
+fn within<G>(graph: &G, from: usize, max_dist: usize) -> HashSet<usize>
+where
+    G: search::Graph<Node = usize>,
+{
+    search::bfs(graph, from, |_| false)
+        .iter()
+        .filter(|(_, d)| *d <= max_dist)
+        .map(|(n, _)| n)
+        .collect()
+}
+
+fn reachable_gates(graph: &Graph, from: usize, max_dist: usize) -> HashSet<usize> {
+    within(graph, from, max_dist)
+}
+
+fn reachable_gates_back(graph: &Graph, from: usize, max_dist: usize) -> HashSet<usize> {
+    within(&Backwards(graph), from, max_dist)
+}
+
+// Bits adding right for all the 8 combinations of their x, y and carry in,
+// 8 bits per batch.
+fn find_good_bits(dev: &Device) -> Vec<usize> {
+    let pairs: Vec<(u64, u64)> = (0..dev.bitwidth)
+        .flat_map(|i| {
+            (0..8).map(move |v| {
+                let carry = if i == 0 { 0 } else { (v >> 2 & 1) << (i - 1) };
+                ((v & 1) << i | carry, (v >> 1 & 1) << i | carry)
+            })
+        })
+        .collect();
+    let mut good = Vec::new();
+    for chunk in pairs.chunks(64) {
+        let z = dev.eval_batch(chunk);
+        good.extend(z.iter().zip(chunk).map(|(z, (x, y))| *z == x + y));
+    }
+    (0..dev.bitwidth)
+        .filter(|i| good[i * 8..i * 8 + 8].iter().all(|g| *g))
+        .collect()
+}
+
+fn try_find_swaps(
+    graph: &mut Graph,
+    bad_gate_candidates: &Vec<usize>,
+    used_bad_gate: &mut Vec<bool>,
+    good_bits: usize,
+    max_swaps: usize,
+    swaps: &mut Vec<usize>,
+    min_i: usize,
+) -> bool {
+    for i in min_i..bad_gate_candidates.len() {
+        if used_bad_gate[i] {
+            continue;
+        }
+        used_bad_gate[i] = true;
+        swaps.push(bad_gate_candidates[i]);
+        for j in i + 1..bad_gate_candidates.len() {
+            if used_bad_gate[j] {
+                continue;
+            }
+            used_bad_gate[j] = true;
+            swaps.push(bad_gate_candidates[j]);
+            graph.swap_gates(bad_gate_candidates[i], bad_gate_candidates[j]);
+
+            if let Some(dev) = Device::try_new(graph) {
+                let new_good_bits = find_good_bits(&dev).len();
+                if (new_good_bits == graph.bitwidth && random_adds(&dev, RANDOM_CHECKS))
+                    || (new_good_bits > good_bits
+                        && max_swaps > 0
+                        && try_find_swaps(
+                            graph,
+                            bad_gate_candidates,
+                            used_bad_gate,
+                            new_good_bits,
+                            max_swaps - 1,
+                            swaps,
+                            i + 1,
+                        ))
+                {
+                    return true;
+                }
+            }
+
+            graph.swap_gates(bad_gate_candidates[i], bad_gate_candidates[j]);
+            swaps.pop();
+            used_bad_gate[j] = false;
+        }
+        swaps.pop();
+        used_bad_gate[i] = false;
+    }
+    false
+}
+
+// `0`, `1`, `NOT a` or inputs joined by the same op like `a AND b AND c`.
+fn parse_gate(input: &str, s: &str) -> ParseResult<(Op, Vec<String>)> {
+    let tokens: Vec<&str> = s.split(' ').collect();
+    let wire = |t: &str| {
+        if !t.is_empty()
+            && t.chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        {
+            Ok(t.to_string())
+        } else {
+            Err(ParseError::new(input, t, "<wire>"))
+        }
+    };
+    match tokens.as_slice() {
+        ["0"] => Ok((Op::ZERO, vec![])),
+        ["1"] => Ok((Op::ONE, vec![])),
+        ["NOT", a] => Ok((Op::NOT, vec![wire(a)?])),
+        [first, op, ..] => {
+            let expected = "AND|OR|XOR|NAND|NOR|XNOR";
+            let gate_op = Op::from_str(op).ok_or_else(|| ParseError::new(input, op, expected))?;
+            let mut ins = vec![wire(first)?];
+            for pair in tokens[1..].chunks(2) {
+                let [op, a] = pair else {
+                    return Err(ParseError::new(input, pair[0], "<op> <wire>"));
+                };
+                if *op != tokens[1] {
+                    return Err(ParseError::new(input, op, tokens[1]));
+                }
+                ins.push(wire(a)?);
+            }
+            Ok((gate_op, ins))
+        }
+        _ => Err(ParseError::new(input, s, "<wire> <op> <wire>")),
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    type Input = Circuit;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        // The initial values are optional, a bare netlist is fine too.
+        let (signals_str, gates_str) = match input.split_once("\n\n") {
+            Some(parts) => parts,
+            None if input.contains("->") => (&input[..0], input),
+            None => parse::split_once(input, input, "\n\n")?,
+        };
+
+        let signal_re = Regex::new(r"^(?<gate>[a-z0-9]+): (?<value>0|1)$").unwrap();
+        let gate_re = Regex::new(r"^(?<ins>[A-Za-z0-9 ]+) -> (?<out>[a-z0-9]+)$").unwrap();
+
+        let signals = signals_str
+            .lines()
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                let c = signal_re
+                    .captures(s)
+                    .ok_or_else(|| ParseError::new(input, s, "<wire>: <0|1>"))?;
+                Ok((c["gate"].to_string(), parse::num(input, &c["value"])?))
+            })
+            .collect::<ParseResult<_>>()?;
+
+        let mut outs = Vec::new();
+        let gates: Vec<GateDesc> = gates_str
+            .trim()
+            .split("\n")
+            .map(|s| {
+                let c = gate_re
+                    .captures(s)
+                    .ok_or_else(|| ParseError::new(input, s, "<gate> -> <wire>"))?;
+                let (op, ins) = parse_gate(input, c.name("ins").unwrap().as_str())?;
+                outs.push(c.name("out").unwrap().as_str());
+                Ok(GateDesc {
+                    ins,
+                    op,
+                    out: c["out"].to_string(),
+                })
+            })
+            .collect::<ParseResult<_>>()?;
+        // Reported at the last gate driving the wire, the second driver or
+        // one on the cycle.
+        if let Err(e) = Netlist::new(&gates) {
+            let (NetlistError::Cycle(w) | NetlistError::Drivers(w)) = &e;
+            let at = outs.iter().rev().find(|o| *o == w).unwrap();
+            let expected = match e {
+                NetlistError::Cycle(_) => "a wire not on a cycle",
+                NetlistError::Drivers(_) => "a wire driven by one gate",
+            };
+            return Err(ParseError::new(input, at, expected));
+        }
+
+        Ok(Circuit { signals, gates })
+    }
+
+    fn part1(circuit: &Self::Input) -> Answer {
+        let netlist = Netlist::new(&circuit.gates).expect("checked by parse");
+        let mut values = vec![false; netlist.len()];
+        for (name, value) in circuit.signals.iter() {
+            if let Some(w) = netlist.wire(name) {
+                values[w] = *value == 1;
+            }
+        }
+        netlist.eval(&mut values);
+        // Too wide for a number, in decimal all the same.
+        let z = netlist.get_bus(&values, "z");
+        match z.to_u64() {
+            Some(v) => v.into(),
+            None => z.to_decimal().into(),
+        }
+    }
+
+    fn part2(circuit: &Self::Input) -> Answer {
+        match adder::verify(&circuit.gates) {
+            Ok(faults) => faults
+                .iter()
+                .flat_map(|f| f.wires.iter())
+                .sorted()
+                .join(",")
+                .into(),
+            Err(_) => search_swaps(circuit),
+        }
+    }
+
+    fn trace(circuit: &Self::Input) -> Option<String> {
+        Some(match adder::verify(&circuit.gates) {
+            Ok(faults) => faults.iter().map(|f| format!("{f}\n")).collect(),
+            Err(e) => format!("not a ripple carry adder at {e}\n"),
+        })
+    }
+
+    // The DOT graph highlights the outputs the adder check found swapped.
+    fn export(circuit: &Self::Input, format: Export) -> Option<String> {
+        let netlist = Netlist::new(&circuit.gates).ok()?;
+        Some(match format {
+            Export::Dot => {
+                let faults = adder::verify(&circuit.gates).unwrap_or_default();
+                let bad: Vec<usize> = faults
+                    .iter()
+                    .flat_map(|f| f.wires.iter())
+                    .filter_map(|w| netlist.wire(w))
+                    .collect();
+                export::dot(&netlist, &bad)
+            }
+            Export::Json => export::json(&netlist),
+        })
+    }
+}
+
+// Brute force search for swaps that make the adder work, for circuits the
+// structural check doesn't understand.
+fn search_swaps(circuit: &Circuit) -> Answer {
+    let bitwidth = circuit.signals.len() / 2;
+    let graph = (bitwidth > 0 && bitwidth < 64)
+        .then(|| Graph::from_gates(bitwidth, &circuit.gates))
+        .flatten();
+    let Some(mut graph) = graph else {
+        return Answer::Unsolved("not an adder".to_string());
+    };
+    let Some(dev) = Device::try_new(&graph) else {
+        return Answer::Unsolved("not an adder".to_string());
+    };
+
+    let good_bits = find_good_bits(&dev);
+    let num_good_bits = good_bits.len();
+
+    let max_gate_depth = 10; // just assumption :shrug:
+    let mut good_gates: HashSet<usize> = HashSet::new();
+    for bit in good_bits {
+        let out = bit + bitwidth * 2;
+        let reach_back = reachable_gates_back(&graph, out, max_gate_depth);
+        for input in [bit, bit + bitwidth] {
+            let reach_front = reachable_gates(&graph, input, max_gate_depth);
+            good_gates.extend(reach_front.intersection(&reach_back));
+        }
+    }
+
+    let mut bad_gate_candidates: HashSet<usize> = HashSet::new();
+
+    for (s_in, s_out) in [
+        (0, bitwidth * 2),            // x_n -> res_n
+        (bitwidth, bitwidth * 2),     // y_n -> res_n
+        (0, bitwidth * 2 + 1),        // x_n -> res_{n+1}
+        (bitwidth, bitwidth * 2 + 1), // y_n -> res_{n+1}
+    ] {
+        let mut paths = HashMap::new();
+        for i in 0..bitwidth {
+            let from = i + s_in;
+            let to = i + s_out;
+            let p = get_shortest_paths(&graph, from, to);
+            paths.entry(p).or_insert(Vec::new()).push(i);
+        }
+
+        let mut with_wrong_paths: Vec<usize> = Vec::new();
+        let mut prev_max = 0;
+        let mut path_len = 0;
+        let mut prev_set: &Vec<usize> = &Vec::new();
+        for (paths, g) in paths.iter() {
+            if g.len() >= prev_max {
+                with_wrong_paths.extend_from_slice(prev_set.as_slice());
+                prev_max = g.len();
+                path_len = paths.len();
+                prev_set = g;
+            } else {
+                with_wrong_paths.extend_from_slice(g.as_slice());
+            }
+        }
+
+        for g in with_wrong_paths {
+            let visited = reachable_gates(&graph, g, path_len);
+            bad_gate_candidates.extend(
+                visited
+                    .into_iter()
+                    .filter(|g| *g > bitwidth * 3)
+                    .filter(|g| !good_gates.contains(g)),
+            );
+        }
+    }
+
+    let bad_gate_candidates = bad_gate_candidates.into_iter().collect_vec();
+
+    let mut swaps = Vec::new();
+    let mut used_bad_gate = Vec::new();
+    used_bad_gate.resize(bad_gate_candidates.len(), false);
+    if !try_find_swaps(
+        &mut graph,
+        &bad_gate_candidates,
+        &mut used_bad_gate,
+        num_good_bits,
+        4,
+        &mut swaps,
+        0,
+    ) {
+        return Answer::Unsolved("no swaps make an adder".to_string());
+    }
+    swaps
+        .iter()
+        .map(|n| graph.nameid.get_str(*n).unwrap())
+        .sorted()
+        .join(",")
+        .into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn batches() {
+        let mut text = String::from("x00 XOR y00 -> z00\nx00 AND y00 -> c00\n");
+        for i in 1..8 {
+            let (p, c) = (
+                i - 1,
+                if i == 7 {
+                    "z08".into()
+                } else {
+                    format!("c{i:02}")
+                },
+            );
+            text += &format!("x{i:02} XOR y{i:02} -> h{i:02}\nx{i:02} AND y{i:02} -> g{i:02}\n");
+            text += &format!("h{i:02} XOR c{p:02} -> z{i:02}\nh{i:02} AND c{p:02} -> t{i:02}\n");
+            text += &format!("g{i:02} OR t{i:02} -> {c}\n");
+        }
+        let adder = Day24::parse(&text).unwrap();
+        let graph = Graph::from_gates(8, &adder.gates).unwrap();
+        let dev = Device::try_new(&graph).unwrap();
+        let pairs = [(0, 0), (255, 1), (100, 200), (37, 91)];
+        assert_eq!(dev.eval_batch(&pairs), vec![0, 256, 300, 128]);
+        assert_eq!(find_good_bits(&dev), (0..8).collect_vec());
+        assert!(random_adds(&dev, RANDOM_CHECKS));
+
+        // Real wires named like the exits.
+        let renamed = Day24::parse(&text.replace("c03", "out04")).unwrap();
+        let graph = Graph::from_gates(8, &renamed.gates).unwrap();
+        let dev = Device::try_new(&graph).unwrap();
+        assert_eq!(dev.eval_batch(&pairs), vec![0, 256, 300, 128]);
+
+        // Wrong only when x01 and y03 are both set, which the single bit
+        // checks never do.
+        let text = text.replace("-> z05", "-> s05") + "x01 AND y03 -> w\ns05 XOR w -> z05\n";
+        let broken = Day24::parse(&text).unwrap();
+        let graph = Graph::from_gates(8, &broken.gates).unwrap();
+        let dev = Device::try_new(&graph).unwrap();
+        assert_eq!(find_good_bits(&dev).len(), 8);
+        assert!(!random_adds(&dev, RANDOM_CHECKS));
+    }
+
+    #[test]
+    fn not_adders() {
+        let unsolved = |text: &str| {
+            let circuit = Day24::parse(text).unwrap();
+            matches!(Day24::part2(&circuit), Answer::Unsolved(_))
+        };
+        assert!(unsolved(include_str!("../../examples/day24.txt")));
+        assert!(unsolved("x00 AND y00 -> z00\n"));
+        assert!(unsolved("x00: 1\ny00: 1\n\nx00 AND y00 -> z00\n"));
+        assert!(unsolved(
+            "x00: 1\ny00: 1\n\nx00 AND y00 -> z00\nx00 OR y00 -> z01\n"
+        ));
+    }
+}
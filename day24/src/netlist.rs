@@ -0,0 +1,370 @@
+use crate::{GateDesc, Op};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Display;
+
+// Little endian bits of a bus value, as wide as the bus.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Bits(pub Vec<bool>);
+
+impl Bits {
+    pub fn from_u64(v: u64, width: usize) -> Bits {
+        Bits((0..width).map(|i| i < 64 && v >> i & 1 == 1).collect())
+    }
+
+    // None if it doesn't fit.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.0.iter().enumerate().rev().try_fold(0, |acc, (i, b)| {
+            if *b && i >= 64 {
+                None
+            } else {
+                Some(acc | (*b as u64) << (i % 64))
+            }
+        })
+    }
+
+    // In base 10, for values of any width.
+    pub fn to_decimal(&self) -> String {
+        // Little endian decimal digits, doubled for every bit.
+        let mut digits = vec![0u8];
+        for b in self.0.iter().rev() {
+            let mut carry = *b as u8;
+            for d in digits.iter_mut() {
+                let v = *d * 2 + carry;
+                *d = v % 10;
+                carry = v / 10;
+            }
+            if carry > 0 {
+                digits.push(carry);
+            }
+        }
+        digits.iter().rev().map(|d| (b'0' + d) as char).collect()
+    }
+}
+
+// Most significant bit first.
+impl Display for Bits {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for b in self.0.iter().rev() {
+            write!(f, "{}", *b as u8)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct NetGate {
+    pub ins: Vec<usize>,
+    pub op: Op,
+}
+
+// Wires named by a prefix and a bit number of at least two digits, like
+// x00, x01, ... Missing bits are None.
+#[derive(Clone, Debug)]
+pub struct Bus {
+    pub name: String,
+    pub wires: Vec<Option<usize>>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum NetlistError {
+    Cycle(String),
+    Drivers(String),
+}
+
+impl Display for NetlistError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetlistError::Cycle(w) => write!(f, "wire {w} is on a cycle"),
+            NetlistError::Drivers(w) => write!(f, "wire {w} is driven by more than one gate"),
+        }
+    }
+}
+
+pub struct Netlist {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    drivers: Vec<Option<NetGate>>,
+    // Wires driven by gates in topological order, so a single pass evaluates.
+    order: Vec<usize>,
+    buses: BTreeMap<String, Bus>,
+}
+
+// Wider buses aren't worth a vector of mostly missing bits, wires with higher
+// bit numbers are plain wires.
+pub const MAX_BUS_WIDTH: usize = 1 << 16;
+
+pub fn bus_bit(name: &str) -> Option<(&str, usize)> {
+    let digits = name.len() - name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+    let (prefix, bit) = name.split_at(name.len() - digits);
+    let bit = bit.parse().ok().filter(|b| *b < MAX_BUS_WIDTH)?;
+    (digits >= 2 && !prefix.is_empty()).then_some((prefix, bit))
+}
+
+impl Netlist {
+    pub fn new(gates: &[GateDesc]) -> Result<Netlist, NetlistError> {
+        let mut names: Vec<String> = Vec::new();
+        let mut ids: HashMap<String, usize> = HashMap::new();
+        let mut id = |s: &str| {
+            *ids.entry(s.to_string()).or_insert_with(|| {
+                names.push(s.to_string());
+                names.len() - 1
+            })
+        };
+        let gates: Vec<(usize, NetGate)> = gates
+            .iter()
+            .map(|g| {
+                let ins = g.ins.iter().map(|i| id(i)).collect();
+                (id(&g.out), NetGate { ins, op: g.op })
+            })
+            .collect();
+
+        let mut drivers: Vec<Option<NetGate>> = vec![None; names.len()];
+        let mut outs: Vec<Vec<usize>> = vec![Vec::new(); names.len()];
+        for (out, g) in gates {
+            for i in g.ins.iter() {
+                outs[*i].push(out);
+            }
+            if drivers[out].replace(g).is_some() {
+                return Err(NetlistError::Drivers(names[out].clone()));
+            }
+        }
+
+        let mut pending: Vec<usize> = drivers
+            .iter()
+            .map(|d| d.as_ref().map_or(0, |g| g.ins.len()))
+            .collect();
+        let mut queue: VecDeque<usize> = (0..names.len()).filter(|w| pending[*w] == 0).collect();
+        let mut order = Vec::new();
+        while let Some(w) = queue.pop_front() {
+            if drivers[w].is_some() {
+                order.push(w);
+            }
+            for next in outs[w].iter() {
+                pending[*next] -= 1;
+                if pending[*next] == 0 {
+                    queue.push_back(*next);
+                }
+            }
+        }
+        // The wires left are on a cycle or after one, going back through the
+        // inputs left ends up going around a cycle.
+        if let Some(mut w) = pending.iter().position(|p| *p > 0) {
+            let mut seen = vec![false; names.len()];
+            while !seen[w] {
+                seen[w] = true;
+                let left = drivers[w].iter().flat_map(|g| g.ins.iter());
+                let Some(i) = left.copied().find(|i| pending[*i] > 0) else {
+                    break;
+                };
+                w = i;
+            }
+            return Err(NetlistError::Cycle(names[w].clone()));
+        }
+
+        let mut buses: BTreeMap<String, Bus> = BTreeMap::new();
+        for (w, name) in names.iter().enumerate() {
+            if let Some((prefix, bit)) = bus_bit(name) {
+                let bus = buses.entry(prefix.to_string()).or_insert_with(|| Bus {
+                    name: prefix.to_string(),
+                    wires: Vec::new(),
+                });
+                if bus.wires.len() <= bit {
+                    bus.wires.resize(bit + 1, None);
+                }
+                bus.wires[bit] = Some(w);
+            }
+        }
+
+        Ok(Netlist {
+            names,
+            ids,
+            drivers,
+            order,
+            buses,
+        })
+    }
+
+    // Number of wires, the wire ids are 0..len().
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn wire(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, wire: usize) -> &str {
+        &self.names[wire]
+    }
+
+    pub fn driver(&self, wire: usize) -> Option<&NetGate> {
+        self.drivers[wire].as_ref()
+    }
+
+    pub fn bus(&self, name: &str) -> Option<&Bus> {
+        self.buses.get(name)
+    }
+
+    pub fn buses(&self) -> impl Iterator<Item = &Bus> {
+        self.buses.values()
+    }
+
+    // The gates with the wires they drive, every gate after its inputs.
+    pub fn gates(&self) -> impl Iterator<Item = (usize, &NetGate)> {
+        self.order
+            .iter()
+            .map(|w| (*w, self.drivers[*w].as_ref().unwrap()))
+    }
+
+    // Wires no gate drives.
+    pub fn inputs(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len()).filter(|w| self.drivers[*w].is_none())
+    }
+
+    // Computes all the gate driven wires from the others in `values`.
+    pub fn eval(&self, values: &mut [bool]) {
+        for (w, g) in self.gates() {
+            values[w] = g.op.eval(g.ins.iter().map(|i| values[*i]));
+        }
+    }
+
+    // Same as eval for 64 sets of values at once, one per bit.
+    pub fn eval_lanes(&self, values: &mut [u64]) {
+        for (w, g) in self.gates() {
+            values[w] = g.op.eval_lanes(g.ins.iter().map(|i| values[*i]));
+        }
+    }
+
+    // Bits past the width of the bus are ignored.
+    pub fn set_bus(&self, values: &mut [bool], name: &str, bits: &Bits) {
+        if let Some(bus) = self.bus(name) {
+            for (w, b) in bus.wires.iter().zip(bits.0.iter()) {
+                if let Some(w) = w {
+                    values[*w] = *b;
+                }
+            }
+        }
+    }
+
+    pub fn get_bus(&self, values: &[bool], name: &str) -> Bits {
+        let wires = self.bus(name).map_or(&[][..], |b| b.wires.as_slice());
+        Bits(wires.iter().map(|w| w.is_some_and(|w| values[w])).collect())
+    }
+
+    // Values of the given input buses, everything else starts as 0.
+    pub fn eval_buses(&self, inputs: &[(&str, &Bits)]) -> Vec<bool> {
+        let mut values = vec![false; self.len()];
+        for (name, bits) in inputs {
+            self.set_bus(&mut values, name, bits);
+        }
+        self.eval(&mut values);
+        values
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Day24;
+    use aoc::solution::Solution;
+
+    #[test]
+    fn wide_bus() {
+        // a + b for 100 bit buses, as a ripple carry adder.
+        let mut text = String::new();
+        for i in 0..100 {
+            text += &format!("a{i:02} XOR b{i:02} -> h{i:02}\na{i:02} AND b{i:02} -> g{i:02}\n");
+            if i == 0 {
+                text += "h00 OR h00 -> s00\ng00 OR g00 -> c00\n";
+            } else {
+                let p = i - 1;
+                text +=
+                    &format!("h{i:02} XOR c{p:02} -> s{i:02}\nh{i:02} AND c{p:02} -> t{i:02}\n");
+                text += &format!("g{i:02} OR t{i:02} -> c{i:02}\n");
+            }
+        }
+        let circuit = Day24::parse(&text).unwrap();
+        let netlist = Netlist::new(&circuit.gates).unwrap();
+        assert_eq!(netlist.bus("s").unwrap().wires.len(), 100);
+
+        let mut a = Bits::from_u64(u64::MAX, 100);
+        a.0[99] = true;
+        let b = Bits::from_u64(1, 100);
+        let values = netlist.eval_buses(&[("a", &a), ("b", &b)]);
+        let mut sum = Bits::from_u64(0, 100);
+        sum.0[64] = true;
+        sum.0[99] = true;
+        assert_eq!(netlist.get_bus(&values, "s"), sum);
+        assert_eq!(sum.to_u64(), None);
+        assert_eq!(sum.to_decimal(), "633825300132561444822061154304");
+        assert_eq!(Bits::from_u64(1234, 20).to_decimal(), "1234");
+        assert!(!netlist.get_bus(&values, "c").0[99]);
+
+        let gate = |a: &str, op, b: &str, out: &str| GateDesc {
+            ins: vec![a.to_string(), b.to_string()],
+            op,
+            out: out.to_string(),
+        };
+        let cycle = [
+            gate("a00", Op::AND, "x", "y"),
+            gate("y", Op::OR, "a00", "x"),
+        ];
+        assert!(matches!(Netlist::new(&cycle), Err(NetlistError::Cycle(_))));
+        // w comes first but is only after the cycle.
+        let after = [
+            gate("w", Op::OR, "a00", "z00"),
+            gate("y", Op::AND, "a00", "w"),
+            gate("a00", Op::AND, "x", "y"),
+            gate("y", Op::OR, "a00", "x"),
+        ];
+        assert_eq!(
+            Netlist::new(&after).err(),
+            Some(NetlistError::Cycle("y".to_string()))
+        );
+
+        assert_eq!(bus_bit("z9999"), Some(("z", 9999)));
+        assert_eq!(bus_bit("z9999999"), None);
+        assert_eq!(bus_bit("z99999999999999999999999"), None);
+    }
+
+    #[test]
+    fn extended_ops() {
+        let text = "1 -> one\n0 -> zero\nNOT a00 -> z00\na00 NAND a01 -> z01\n\
+            a00 NOR a01 -> z02\na00 XNOR a01 -> z03\na00 XOR a01 XOR one -> z04\n\
+            a00 AND a01 AND one -> z05\nzero OR a00 OR a01 -> z06\n";
+        let circuit = Day24::parse(text).unwrap();
+        let netlist = Netlist::new(&circuit.gates).unwrap();
+        let z = |a| {
+            let values = netlist.eval_buses(&[("a", &Bits::from_u64(a, 2))]);
+            netlist.get_bus(&values, "z").to_u64().unwrap()
+        };
+        assert_eq!(
+            [0, 1, 2, 3].map(z),
+            [0b0011111, 0b1000010, 0b1000011, 0b1111000]
+        );
+
+        for bad in [
+            "a AND b OR c -> d\n",
+            "NOT a b -> c\n",
+            "a AND -> b\n",
+            "2 -> a\n",
+        ] {
+            assert!(Day24::parse(bad).is_err(), "{bad}");
+        }
+        let cycle = Day24::parse("a00 AND x -> y\ny OR a00 -> x\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (cycle.line, cycle.expected.as_str()),
+            (2, "a wire not on a cycle")
+        );
+        let twice = Day24::parse("a00 AND a01 -> y\na00 OR a01 -> y\n")
+            .err()
+            .unwrap();
+        assert_eq!((twice.line, twice.column), (2, 15));
+    }
+}
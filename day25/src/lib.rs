@@ -0,0 +1,56 @@
+use aoc::grid::{Grid, Pos};
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, ParseResult, Solution};
+
+fn key_matches_lock(key: &[u8; 5], lock: &[u8; 5]) -> bool {
+    key.iter().zip(lock).all(|(k, l)| k + l <= 5)
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    type Input = (Vec<[u8; 5]>, Vec<[u8; 5]>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut keys: Vec<[u8; 5]> = Vec::new();
+        let mut locks: Vec<[u8; 5]> = Vec::new();
+        for entry in input.trim().split("\n\n") {
+            let kl: Grid<u8> = entry
+                .parse()
+                .map_err(|e: ParseError| e.rebase(input, entry))?;
+            if kl.width() != 5 || kl.height() != 7 {
+                return Err(ParseError::new(input, entry, "5x7 key or lock"));
+            }
+            let mut heights: [u8; 5] = [0; 5];
+            for (x, h) in heights.iter_mut().enumerate() {
+                *h = (1..=5)
+                    .filter(|y| kl[Pos::new(x as i32, *y)] == b'#')
+                    .count() as u8;
+            }
+            if kl[Pos::new(0, 0)] == b'#' {
+                locks.push(heights);
+            } else {
+                keys.push(heights);
+            }
+        }
+        Ok((keys, locks))
+    }
+
+    fn part1((keys, locks): &Self::Input) -> Answer {
+        let mut res = 0;
+        for k in keys.iter() {
+            for l in locks.iter() {
+                if key_matches_lock(k, l) {
+                    res += 1;
+                }
+            }
+        }
+        res.into()
+    }
+
+    // There's no second puzzle on the last day.
+    fn part2(_input: &Self::Input) -> Answer {
+        "".into()
+    }
+}
@@ -0,0 +1,60 @@
+use aoc::parse;
+use aoc::solution::{Answer, ParseResult, Solution};
+use regex::Regex;
+
+pub enum Op {
+    Do,
+    Dont,
+    Mul(i64, i64),
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Vec<Op>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let re = Regex::new(r"(mul)\((\d+),(\d+)\)|(do)\(\)|(don't)\(\)").unwrap();
+        re.captures_iter(input)
+            .map(
+                |c| match c.get(1).or(c.get(4)).or(c.get(5)).unwrap().as_str() {
+                    "mul" => Ok(Op::Mul(
+                        parse::num(input, &c[2])?,
+                        parse::num(input, &c[3])?,
+                    )),
+                    "do" => Ok(Op::Do),
+                    "don't" => Ok(Op::Dont),
+                    _ => panic!("Unexpected value {}", &c[0]),
+                },
+            )
+            .collect()
+    }
+
+    fn part1(ops: &Self::Input) -> Answer {
+        let mut sum = 0;
+        for op in ops {
+            if let Op::Mul(a, b) = op {
+                sum += a * b;
+            }
+        }
+        sum.into()
+    }
+
+    fn part2(ops: &Self::Input) -> Answer {
+        let mut sum = 0;
+        let mut enabled = true;
+        for op in ops {
+            match op {
+                Op::Do => enabled = true,
+                Op::Dont => enabled = false,
+                Op::Mul(a, b) => {
+                    if enabled {
+                        sum += a * b
+                    }
+                }
+            }
+        }
+        sum.into()
+    }
+}
@@ -0,0 +1,60 @@
+use aoc::grid::{Grid, Pos};
+use aoc::solution::{Answer, ParseResult, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input.parse()
+    }
+
+    fn part1(grid: &Self::Input) -> Answer {
+        let pattern = "XMAS".as_bytes();
+        let mut found = 0;
+
+        for xi in -1..=1 {
+            for yi in -1..=1 {
+                if xi == 0 && yi == 0 {
+                    continue;
+                }
+                'outer: for pos in grid.iter() {
+                    for (off, c) in pattern.iter().enumerate() {
+                        let off = off as i32;
+                        let p = Pos::new(pos.x + off * xi, pos.y + off * yi);
+                        if grid.get(p) != Some(c) {
+                            continue 'outer;
+                        }
+                    }
+                    found += 1;
+                }
+            }
+        }
+        found.into()
+    }
+
+    fn part2(grid: &Self::Input) -> Answer {
+        let mut found = 0;
+        for pos in grid.iter() {
+            if pos.x == 0
+                || pos.y == 0
+                || pos.x as usize == grid.width() - 1
+                || pos.y as usize == grid.height() - 1
+                || grid[pos] != b'A'
+            {
+                continue;
+            }
+            let at = |dx, dy| grid[Pos::new(pos.x + dx, pos.y + dy)];
+            let d1 = (at(-1, -1), at(1, 1));
+            let d2 = (at(1, -1), at(-1, 1));
+            let var1 = (b'M', b'S');
+            let var2 = (b'S', b'M');
+            if (d1 == var1 || d1 == var2) && (d2 == var1 || d2 == var2) {
+                found += 1;
+            }
+        }
+        found.into()
+    }
+}
@@ -0,0 +1,86 @@
+use aoc::parse;
+use aoc::solution::{Answer, ParseResult, Solution};
+use std::collections::{HashMap, HashSet};
+
+type Rules = HashMap<i32, HashSet<i32>>;
+
+fn is_valid_order(rules: &Rules, pages: &[i32]) -> bool {
+    let mut prev_pages = HashSet::new();
+    for p in pages {
+        if let Some(rule) = rules.get(p) {
+            if !rule.is_disjoint(&prev_pages) {
+                return false;
+            }
+        }
+        prev_pages.insert(*p);
+    }
+    true
+}
+
+fn valid_pages_order(rules: &Rules, p1: i32, p2: i32) -> bool {
+    if let Some(rule) = rules.get(&p2) {
+        return !rule.contains(&p1);
+    }
+    true
+}
+
+fn sort_pages(rules: &Rules, pages: &mut [i32]) -> bool {
+    let mut changed = false;
+    for i in 1..pages.len() {
+        for j in 0..i {
+            if !valid_pages_order(rules, pages[j], pages[i]) {
+                pages[j..=i].rotate_right(1);
+                changed = true;
+                break;
+            }
+        }
+    }
+    changed
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = (Rules, Vec<Vec<i32>>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let mut lines = input.lines();
+        let mut rules = HashMap::new();
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            let (a, b) = parse::split_once(input, line, "|")?;
+            rules
+                .entry(parse::num(input, a)?)
+                .or_insert(HashSet::new())
+                .insert(parse::num(input, b)?);
+        }
+        let updates = lines
+            .map(|line| line.split(",").map(|x| parse::num(input, x)).collect())
+            .collect::<ParseResult<_>>()?;
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> Answer {
+        let mut result = 0;
+        for pages in updates {
+            if is_valid_order(rules, pages) {
+                result += pages[pages.len() / 2];
+            }
+        }
+        result.into()
+    }
+
+    fn part2((rules, updates): &Self::Input) -> Answer {
+        let mut result = 0;
+        for pages in updates {
+            let mut pages = pages.clone();
+            if sort_pages(rules, &mut pages) {
+                result += pages[pages.len() / 2];
+            }
+        }
+        result.into()
+    }
+}
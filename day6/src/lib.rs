@@ -0,0 +1,140 @@
+use aoc::grid::{self, Grid, Pos};
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, ParseResult, Solution};
+
+#[derive(Clone, Copy)]
+enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    fn rot_right(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Down,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Up,
+        }
+    }
+
+    fn bit(self) -> u8 {
+        match self {
+            Dir::Up => 1 << 0,
+            Dir::Right => 1 << 1,
+            Dir::Down => 1 << 2,
+            Dir::Left => 1 << 3,
+        }
+    }
+}
+
+impl grid::Dir for Dir {
+    fn vector(&self) -> (i32, i32) {
+        match self {
+            Dir::Up => (0, -1),
+            Dir::Right => (1, 0),
+            Dir::Down => (0, 1),
+            Dir::Left => (-1, 0),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct DirSet(u8);
+
+impl DirSet {
+    fn new() -> Self {
+        DirSet(0)
+    }
+
+    fn set(&mut self, dir: Dir) {
+        self.0 |= dir.bit();
+    }
+
+    fn has(self, dir: Dir) -> bool {
+        self.0 & dir.bit() != 0
+    }
+}
+
+fn has_loop(grid: &Grid<u8>, visited: &mut Grid<DirSet>, start: Pos) -> bool {
+    let mut pos = start;
+    let mut dir = Dir::Up;
+    visited.fill(DirSet::new());
+    while grid.in_bounds(pos) {
+        while grid.get(pos.adv(dir)) == Some(&b'#') {
+            dir = dir.rot_right();
+        }
+        if visited[pos].has(dir) {
+            return true;
+        }
+        visited[pos].set(dir);
+        pos = pos.adv(dir);
+    }
+    false
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    type Input = (Grid<u8>, Pos);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let grid: Grid<u8> = input.parse()?;
+        let start = grid
+            .find(&b'^')
+            .ok_or_else(|| ParseError::new(input, &input[input.len()..], "guard tile '^'"))?;
+        Ok((grid, start))
+    }
+
+    fn part1((grid, start): &Self::Input) -> Answer {
+        let mut g = grid.clone();
+        let mut pos = *start;
+
+        // Just naively simulate
+        let mut dir = Dir::Up;
+        while g.in_bounds(pos) {
+            while g.get(pos.adv(dir)) == Some(&b'#') {
+                dir = dir.rot_right();
+            }
+            g[pos] = b'X';
+            pos = pos.adv(dir);
+        }
+
+        // Count number of visited positions
+        let result = g.iter().filter(|pos| g[*pos] == b'X').count();
+        result.into()
+    }
+
+    fn part2((grid, start_pos): &Self::Input) -> Answer {
+        let mut g = grid.clone();
+        let start_pos = *start_pos;
+        let mut visited = Grid::new(g.width(), g.height(), DirSet::new());
+
+        let mut result = 0;
+        for obstacle in g.iter() {
+            if g[obstacle] != b'.' {
+                continue;
+            }
+            g[obstacle] = b'#';
+            if has_loop(&g, &mut visited, start_pos) {
+                result += 1;
+            }
+            g[obstacle] = b'.';
+        }
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tiles() {
+        let err = Day6::parse("#.#\n...\n").err().unwrap();
+        assert_eq!(err.expected, "guard tile '^'");
+    }
+}
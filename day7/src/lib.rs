@@ -0,0 +1,110 @@
+use aoc::parse;
+use aoc::solution::{Answer, ParseResult, Solution};
+
+fn can_eval(res: i64, vals: &[i64]) -> bool {
+    if let Some((last, rest)) = vals.split_last() {
+        let last = *last;
+        return if res >= last {
+            can_eval(res - last, rest)
+        } else {
+            false
+        } || if res > 0 && res % last == 0 {
+            can_eval(res / last, rest)
+        } else {
+            false
+        };
+    }
+    res == 0
+}
+
+fn digit_multipler(val: i64) -> i64 {
+    let mut multiplier = 10;
+    while multiplier <= val {
+        multiplier *= 10;
+    }
+    multiplier
+}
+
+fn can_eval_concat(res: i64, vals: &[(i64, i64)]) -> bool {
+    if let Some(((last, dm), rest)) = vals.split_last() {
+        let (last, dm) = (*last, *dm);
+        return (res >= last && can_eval_concat(res - last, rest))
+            || (res > 0 && res % last == 0 && can_eval_concat(res / last, rest))
+            || (res > dm && res % dm == last && can_eval_concat(res / dm, rest));
+    }
+    res == 0
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    type Input = Vec<(i64, Vec<i64>)>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        input
+            .lines()
+            .map(|line| {
+                let (calibration, vals) = parse::split_once(input, line, ":")?;
+                let vals = vals
+                    .trim()
+                    .split(" ")
+                    .map(|x| parse::num(input, x))
+                    .collect::<ParseResult<_>>()?;
+                Ok((parse::num(input, calibration)?, vals))
+            })
+            .collect()
+    }
+
+    fn part1(equations: &Self::Input) -> Answer {
+        let mut result = 0;
+        for (calibration, vals) in equations {
+            if can_eval(*calibration, vals) {
+                result += calibration;
+            }
+        }
+        result.into()
+    }
+
+    fn part2(equations: &Self::Input) -> Answer {
+        let mut result = 0;
+        for (calibration, vals) in equations {
+            let vals: Vec<(i64, i64)> = vals.iter().map(|v| (*v, digit_multipler(*v))).collect();
+            if can_eval_concat(*calibration, &vals) {
+                result += calibration;
+            }
+        }
+        result.into()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn simple() {
+        assert!(can_eval(0, &[]));
+        assert!(can_eval(10, &[10]));
+        assert!(can_eval(10, &[1, 10]));
+        assert!(!can_eval(10, &[1, 8]));
+        assert!(!can_eval(10, &[2, 10]));
+    }
+
+    #[test]
+    fn simple_concat() {
+        assert!(can_eval_concat(0, &[]));
+        assert!(can_eval_concat(10, &[(10, 100)]));
+        assert!(can_eval_concat(10, &[(1, 10), (10, 100)]));
+        assert!(!can_eval_concat(10, &[(1, 10), (8, 10)]));
+        assert!(!can_eval_concat(10, &[(2, 10), (10, 100)]));
+        assert!(can_eval_concat(156, &[(15, 100), (6, 10)]));
+    }
+
+    #[test]
+    fn dm() {
+        assert_eq!(digit_multipler(1), 10);
+        assert_eq!(digit_multipler(100), 1000);
+        assert_eq!(digit_multipler(99), 100);
+    }
+}
@@ -0,0 +1,54 @@
+use aoc::grid::{Grid, Pos};
+use aoc::solution::{Answer, ParseResult, Solution};
+use std::collections::{HashMap, HashSet};
+
+fn count_antinodes(grid: &Grid<u8>, antenas: &HashMap<u8, Vec<Pos>>, resonant: bool) -> usize {
+    let mut antinodes: HashSet<Pos> = HashSet::new();
+    for same_antenas in antenas.values() {
+        for a1 in same_antenas {
+            for a2 in same_antenas {
+                if a1 == a2 {
+                    continue;
+                }
+                let diff = (a2.x - a1.x, a2.y - a1.y);
+                if resonant {
+                    let mut an = *a2;
+                    while grid.in_bounds(an) {
+                        antinodes.insert(an);
+                        an = an.adv(diff);
+                    }
+                } else {
+                    let antinode = a2.adv(diff);
+                    if grid.in_bounds(antinode) {
+                        antinodes.insert(antinode);
+                    }
+                }
+            }
+        }
+    }
+    antinodes.len()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = (Grid<u8>, HashMap<u8, Vec<Pos>>);
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let grid: Grid<u8> = input.parse()?;
+        let mut antenas: HashMap<u8, Vec<Pos>> = HashMap::new();
+        for pos in grid.iter().filter(|pos| grid[*pos] != b'.') {
+            antenas.entry(grid[pos]).or_default().push(pos);
+        }
+        Ok((grid, antenas))
+    }
+
+    fn part1((grid, antenas): &Self::Input) -> Answer {
+        count_antinodes(grid, antenas, false).into()
+    }
+
+    fn part2((grid, antenas): &Self::Input) -> Answer {
+        count_antinodes(grid, antenas, true).into()
+    }
+}
@@ -0,0 +1,91 @@
+use aoc::parse::ParseError;
+use aoc::solution::{Answer, ParseResult, Solution};
+use std::cmp;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> ParseResult<Self::Input> {
+        let disk_map = input.trim();
+        disk_map
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_digit(10)
+                    .map(|d| d as u64)
+                    .ok_or_else(|| ParseError::new(input, &disk_map[i..], "digit"))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        let mut input = input.clone();
+
+        let mut checksum: u64 = 0;
+        let mut i = 0;
+        let mut j = input.len() - 1;
+        if j % 2 == 1 {
+            j -= 1;
+        }
+        let mut off = 0;
+        while i <= j {
+            let next_off = off + input[i];
+            checksum += (i as u64 / 2)
+                * (next_off * next_off.saturating_sub(1) - off * off.saturating_sub(1))
+                / 2;
+            off = next_off;
+
+            i += 1;
+            while i < j && input[i] > 0 {
+                let d = cmp::min(input[i], input[j]);
+                input[i] -= d;
+                input[j] -= d;
+
+                let next_off = off + d;
+                checksum += (j as u64 / 2)
+                    * (next_off * next_off.saturating_sub(1) - off * off.saturating_sub(1))
+                    / 2;
+                off = next_off;
+
+                if input[j] == 0 {
+                    j -= 2;
+                }
+            }
+            i += 1;
+        }
+        checksum.into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        let mut input = input.clone();
+
+        let mut off: Vec<u64> = input
+            .iter()
+            .scan(0, |s, v| {
+                let r = *s;
+                *s += v;
+                Some(r)
+            })
+            .collect();
+
+        let mut checksum: u64 = 0;
+        for j in (0..input.len()).step_by(2).rev() {
+            let mut pos = j;
+            for i in (1..j).step_by(2) {
+                if input[i] >= input[j] {
+                    pos = i;
+                    break;
+                }
+            }
+            let prev_off = off[pos];
+            off[pos] += input[j];
+            checksum += (j as u64 / 2)
+                * (off[pos] * off[pos].saturating_sub(1) - prev_off * prev_off.saturating_sub(1))
+                / 2;
+            input[pos] -= input[j];
+        }
+        checksum.into()
+    }
+}
@@ -0,0 +1,88 @@
+use aoc::parse::ParseError;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+// Known answers per input file, same format as examples/answers.txt:
+// <input> <part 1 answer> <part 2 answer>, "-" for an unknown answer.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Answers {
+    entries: BTreeMap<String, [Option<String>; 2]>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Check {
+    New,
+    Same,
+    Changed(String),
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers, ParseError> {
+        let mut entries = BTreeMap::new();
+        for line in input.lines().filter(|l| !l.starts_with('#')) {
+            let [file, part1, part2] = line.split(' ').collect::<Vec<_>>()[..] else {
+                return Err(ParseError::new(input, line, "<input> <part 1> <part 2>"));
+            };
+            let known = |a: &str| (a != "-").then(|| a.to_string());
+            entries.insert(file.to_string(), [known(part1), known(part2)]);
+        }
+        Ok(Answers { entries })
+    }
+
+    pub fn get(&self, file: &str, part: usize) -> Option<&str> {
+        self.entries.get(file)?[part - 1].as_deref()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, [Option<&str>; 2])> {
+        self.entries
+            .iter()
+            .map(|(file, [a, b])| (file.as_str(), [a.as_deref(), b.as_deref()]))
+    }
+
+    // Records the answer unless there's one already, a recorded answer is
+    // never overwritten, a wrong one has to be removed by hand.
+    pub fn check(&mut self, file: &str, part: usize, answer: &str) -> Check {
+        let entry = self.entries.entry(file.to_string()).or_default();
+        match &entry[part - 1] {
+            Some(known) if known == answer => Check::Same,
+            Some(known) => Check::Changed(known.clone()),
+            None => {
+                entry[part - 1] = Some(answer.to_string());
+                Check::New
+            }
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (file, answers) in self.iter() {
+            let [a, b] = answers.map(|a| a.unwrap_or("-"));
+            writeln!(f, "{file} {a} {b}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let mut answers = Answers::parse("# comment\nday01.txt 11 -\n").unwrap();
+        assert_eq!(answers.check("day01.txt", 1, "11"), Check::Same);
+        assert_eq!(
+            answers.check("day01.txt", 1, "12"),
+            Check::Changed("11".into())
+        );
+        assert_eq!(answers.check("day01.txt", 2, "31"), Check::New);
+        assert_eq!(answers.check("day02.txt", 1, "2"), Check::New);
+        assert_eq!(answers.get("day01.txt", 1), Some("11"));
+        assert_eq!(answers.get("day02.txt", 2), None);
+        assert_eq!(answers.to_string(), "day01.txt 11 31\nday02.txt 2 -\n");
+
+        let err = Answers::parse("day01.txt 11\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
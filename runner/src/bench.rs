@@ -0,0 +1,134 @@
+use aoc::parse::ParseError;
+use aoc::solution::Day;
+use std::cmp::min;
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub struct Timing {
+    pub day: usize,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+fn time<T>(mut f: impl FnMut() -> T) -> (Duration, T) {
+    let start = Instant::now();
+    let res = black_box(f());
+    (start.elapsed(), res)
+}
+
+// Best of `runs` for each of the stages, the minimum is the least noisy.
+pub fn bench_day(
+    day: usize,
+    solution: &Day,
+    input: &str,
+    runs: usize,
+) -> Result<Timing, ParseError> {
+    let mut timing = Timing {
+        day,
+        parse: Duration::MAX,
+        part1: Duration::MAX,
+        part2: Duration::MAX,
+    };
+    for _ in 0..runs {
+        let (t, parsed) = time(|| (solution.parse)(black_box(input)));
+        let parsed = parsed?;
+        timing.parse = min(timing.parse, t);
+        timing.part1 = min(timing.part1, time(|| (solution.part1)(parsed.as_ref())).0);
+        timing.part2 = min(timing.part2, time(|| (solution.part2)(parsed.as_ref())).0);
+    }
+    Ok(timing)
+}
+
+fn ms(d: Duration) -> String {
+    format!("{:.3}", d.as_secs_f64() * 1000.0)
+}
+
+pub fn table(timings: &[Timing]) -> String {
+    let mut out = String::new();
+    let row = |out: &mut String, name: &str, cols: [Duration; 4]| {
+        let [parse, part1, part2, total] = cols.map(ms);
+        writeln!(
+            out,
+            "{name:>5} {parse:>12} {part1:>12} {part2:>12} {total:>12}"
+        )
+        .unwrap();
+    };
+    writeln!(
+        out,
+        "{:>5} {:>12} {:>12} {:>12} {:>12}",
+        "day", "parse ms", "part1 ms", "part2 ms", "total ms"
+    )
+    .unwrap();
+    for t in timings {
+        row(
+            &mut out,
+            &t.day.to_string(),
+            [t.parse, t.part1, t.part2, t.total()],
+        );
+    }
+    let sum = |f: fn(&Timing) -> Duration| timings.iter().map(f).sum();
+    row(
+        &mut out,
+        "all",
+        [
+            sum(|t| t.parse),
+            sum(|t| t.part1),
+            sum(|t| t.part2),
+            sum(|t| t.total()),
+        ],
+    );
+    out
+}
+
+pub fn json(timings: &[Timing], runs: usize) -> String {
+    let days: Vec<String> = timings
+        .iter()
+        .map(|t| {
+            format!(
+                r#"{{"day": {}, "parse_ns": {}, "part1_ns": {}, "part2_ns": {}}}"#,
+                t.day,
+                t.parse.as_nanos(),
+                t.part1.as_nanos(),
+                t.part2.as_nanos()
+            )
+        })
+        .collect();
+    format!(
+        "{{\"runs\": {}, \"days\": [\n  {}\n]}}\n",
+        runs,
+        days.join(",\n  ")
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn output() {
+        let timings = [Timing {
+            day: 3,
+            parse: Duration::from_micros(1500),
+            part1: Duration::from_nanos(20),
+            part2: Duration::from_millis(2),
+        }];
+        let table = table(&timings);
+        assert!(table
+            .lines()
+            .nth(1)
+            .unwrap()
+            .ends_with("1.500        0.000        2.000        3.500"));
+        assert_eq!(
+            json(&timings, 5),
+            "{\"runs\": 5, \"days\": [\n  {\"day\": 3, \"parse_ns\": 1500000, \"part1_ns\": 20, \"part2_ns\": 2000000}\n]}\n"
+        );
+    }
+}
@@ -0,0 +1,32 @@
+pub mod answers;
+pub mod bench;
+
+use aoc::solution::Day;
+
+pub const DAYS: [Day; 25] = [
+    Day::new::<aoc2024day1::Day1>(),
+    Day::new::<aoc2024day2::Day2>(),
+    Day::new::<aoc2024day3::Day3>(),
+    Day::new::<aoc2024day4::Day4>(),
+    Day::new::<aoc2024day5::Day5>(),
+    Day::new::<aoc2024day6::Day6>(),
+    Day::new::<aoc2024day7::Day7>(),
+    Day::new::<aoc2024day8::Day8>(),
+    Day::new::<aoc2024day9::Day9>(),
+    Day::new::<aoc2024day10::Day10>(),
+    Day::new::<aoc2024day11::Day11>(),
+    Day::new::<aoc2024day12::Day12>(),
+    Day::new::<aoc2024day13::Day13>(),
+    Day::new::<aoc2024day14::Day14>(),
+    Day::new::<aoc2024day15::Day15>(),
+    Day::new::<aoc2024day16::Day16>(),
+    Day::new::<aoc2024day17::Day17>(),
+    Day::new::<aoc2024day18::Day18>(),
+    Day::new::<aoc2024day19::Day19>(),
+    Day::new::<aoc2024day20::Day20>(),
+    Day::new::<aoc2024day21::Day21>(),
+    Day::new::<aoc2024day22::Day22>(),
+    Day::new::<aoc2024day23::Day23>(),
+    Day::new::<aoc2024day24::Day24>(),
+    Day::new::<aoc2024day25::Day25>(),
+];
@@ -0,0 +1,357 @@
+use aoc::solution::{Answer, Export};
+use aoc2024::answers::{Answers, Check};
+use aoc2024::bench;
+use aoc2024::DAYS;
+use aoc2024day17::debug::Debugger;
+use aoc2024day17::Computer;
+use aoc2024day23::lan::{self, Command};
+use aoc2024day23::Network;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+// Default place for the puzzle inputs and the answers recorded for them.
+const INPUTS: &str = "inputs";
+
+fn input_file(day: usize) -> String {
+    format!("day{:02}.txt", day)
+}
+
+// Checks the answers against the ones recorded in `dir/answers.txt` and with
+// `save` records the new ones, false if any disagrees with a recorded one.
+fn record(dir: &Path, results: &[(String, usize, String)], save: bool) -> bool {
+    let path = dir.join("answers.txt");
+    let mut answers = match fs::read_to_string(&path) {
+        Ok(s) => match Answers::parse(&s) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("error: {}: {e}", path.display());
+                return false;
+            }
+        },
+        Err(_) => Answers::default(),
+    };
+    let mut ok = true;
+    let mut changed = false;
+    for (file, part, answer) in results {
+        // Nothing to check for answers like the day 25 part 2 freebie.
+        if answer.is_empty() {
+            continue;
+        }
+        match answers.check(file, *part, answer) {
+            Check::Same => {}
+            Check::New => changed = true,
+            Check::Changed(known) => {
+                eprintln!("error: {file} part {part}: got {answer}, recorded {known}");
+                ok = false;
+            }
+        }
+    }
+    if changed && save {
+        if let Err(e) = fs::write(&path, answers.to_string()) {
+            eprintln!("error: {}: {e}", path.display());
+            ok = false;
+        }
+    }
+    ok
+}
+
+fn parse_day(s: &str) -> Option<usize> {
+    s.parse().ok().filter(|d| (1..=DAYS.len()).contains(d))
+}
+
+fn parse_part(s: &str) -> Option<usize> {
+    match s {
+        "a" | "1" => Some(1),
+        "b" | "2" => Some(2),
+        _ => None,
+    }
+}
+
+fn run_all(dir: &Path) -> bool {
+    let mut ok = true;
+    let mut results = Vec::new();
+    for (day, solution) in DAYS.iter().enumerate() {
+        let day = day + 1;
+        let Ok(input) = fs::read_to_string(dir.join(input_file(day))) else {
+            continue;
+        };
+        let input = match (solution.parse)(&input) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("error: {e}");
+                ok = false;
+                continue;
+            }
+        };
+        for (part, f) in [(1, solution.part1), (2, solution.part2)] {
+            match f(input.as_ref()) {
+                Answer::Unsolved(why) => {
+                    eprintln!("error: day {day} part {part}: no answer: {why}");
+                    ok = false;
+                }
+                answer => {
+                    println!("day {:2} part {}: {}", day, part, answer);
+                    results.push((input_file(day), part, answer.to_string()));
+                }
+            }
+        }
+    }
+    // Other directories like examples/ are only checked, never written to.
+    record(dir, &results, dir == Path::new(INPUTS)) && ok
+}
+
+// The input from stdin with `stdin`, otherwise from the inputs directory
+// together with that directory.
+fn read_input(day: usize, stdin: bool) -> Option<(String, Option<&'static Path>)> {
+    let (input, dir) = if stdin {
+        (io::read_to_string(io::stdin()), None)
+    } else {
+        let path: PathBuf = [INPUTS, &input_file(day)].iter().collect();
+        let input = fs::read_to_string(&path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {e}", path.display())));
+        (input, Some(Path::new(INPUTS)))
+    };
+    match input {
+        Ok(input) => Some((input, dir)),
+        Err(e) => {
+            eprintln!("error: {e}");
+            None
+        }
+    }
+}
+
+// Only answers for inputs from the inputs directory are recorded. With
+// `trace` the execution log goes to stderr first, day 17 stops it where the
+// debugger says.
+fn run_one(day: usize, part: usize, stdin: bool, trace: Option<Debugger>) -> bool {
+    let Some((input, dir)) = read_input(day, stdin) else {
+        return false;
+    };
+    let solution = &DAYS[day - 1];
+    let input = match (solution.parse)(&input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return false;
+        }
+    };
+    if let Some(debugger) = trace {
+        let log = match input.downcast_ref::<Computer>() {
+            Some(computer) => Some(aoc2024day17::trace_with(computer, &debugger)),
+            None if !debugger.breakpoints.is_empty() || debugger.step_limit.is_some() => {
+                eprintln!("error: --break and --steps are only for day 17");
+                return false;
+            }
+            None => (solution.trace)(input.as_ref()),
+        };
+        match log {
+            Some(log) => eprint!("{log}"),
+            None => {
+                eprintln!("error: no trace for day {day}");
+                return false;
+            }
+        }
+    }
+    let f = if part == 1 {
+        solution.part1
+    } else {
+        solution.part2
+    };
+    let answer = match f(input.as_ref()) {
+        Answer::Unsolved(why) => {
+            eprintln!("error: no answer: {why}");
+            return false;
+        }
+        answer => answer.to_string(),
+    };
+    println!("{answer}");
+    dir.is_none_or(|dir| record(dir, &[(input_file(day), part, answer)], true))
+}
+
+fn export(day: usize, format: Export, stdin: bool) -> bool {
+    let Some((input, _)) = read_input(day, stdin) else {
+        return false;
+    };
+    let solution = &DAYS[day - 1];
+    let input = match (solution.parse)(&input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return false;
+        }
+    };
+    match (solution.export)(input.as_ref(), format) {
+        Some(out) => {
+            print!("{out}");
+            true
+        }
+        None => {
+            eprintln!("error: no export for day {day}");
+            false
+        }
+    }
+}
+
+// Day 23's reports on its network.
+fn lan(command: Command, stdin: bool) -> bool {
+    let Some((input, _)) = read_input(23, stdin) else {
+        return false;
+    };
+    let input = match (DAYS[22].parse)(&input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {e}");
+            return false;
+        }
+    };
+    let net: &Network = input.downcast_ref().unwrap();
+    print!("{}", command.run(net));
+    true
+}
+
+fn run_bench(dir: &Path, runs: usize, json: bool) -> bool {
+    let mut ok = true;
+    let mut timings = Vec::new();
+    for (day, solution) in DAYS.iter().enumerate() {
+        let day = day + 1;
+        let Ok(input) = fs::read_to_string(dir.join(input_file(day))) else {
+            continue;
+        };
+        match bench::bench_day(day, solution, &input, runs) {
+            Ok(timing) => timings.push(timing),
+            Err(e) => {
+                eprintln!("error: {e}");
+                ok = false;
+            }
+        }
+    }
+    if json {
+        print!("{}", bench::json(&timings, runs));
+    } else {
+        print!("{}", bench::table(&timings));
+    }
+    ok
+}
+
+fn parse_bench_opts(opts: &[&str]) -> Option<(usize, bool)> {
+    let mut runs = 3;
+    let mut json = false;
+    let mut opts = opts.iter();
+    while let Some(opt) = opts.next() {
+        match *opt {
+            "--json" => json = true,
+            "--runs" => runs = opts.next()?.parse().ok().filter(|r| *r > 0)?,
+            _ => return None,
+        }
+    }
+    Some((runs, json))
+}
+
+// `-` reads the input from stdin. --break and --steps only make sense with a
+// trace, so they turn it on.
+fn parse_run_opts(opts: &[&str]) -> Option<(bool, Option<Debugger>)> {
+    let mut stdin = false;
+    let mut trace: Option<Debugger> = None;
+    let mut opts = opts.iter();
+    while let Some(opt) = opts.next() {
+        match *opt {
+            "-" => stdin = true,
+            "--trace" => {
+                trace.get_or_insert_default();
+            }
+            "--break" => trace
+                .get_or_insert_default()
+                .breakpoints
+                .push(opts.next()?.parse().ok()?),
+            "--steps" => {
+                trace.get_or_insert_default().step_limit = Some(opts.next()?.parse().ok()?)
+            }
+            _ => return None,
+        }
+    }
+    Some((stdin, trace))
+}
+
+fn usage(prog: &str) -> ExitCode {
+    println!("{prog} run <day> <a|b> [--trace] [--break IP].. [--steps N] [- < input.txt]");
+    println!("{prog} run --all [dir]");
+    println!("{prog} export <day> --dot|--json [- < input.txt]");
+    for command in lan::USAGE {
+        println!("{prog} lan {command} [- < input.txt]");
+    }
+    println!("{prog} bench <dir> [--runs N] [--json]");
+    ExitCode::FAILURE
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().collect();
+    let args: Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    match args.as_slice() {
+        [_, "run", "--all"] => {
+            if !run_all(Path::new(INPUTS)) {
+                return ExitCode::FAILURE;
+            }
+        }
+        [_, "run", "--all", dir] => {
+            if !run_all(Path::new(dir)) {
+                return ExitCode::FAILURE;
+            }
+        }
+        [prog, "run", day, part, opts @ ..] => {
+            let (Some(day), Some(part), Some((stdin, trace))) =
+                (parse_day(day), parse_part(part), parse_run_opts(opts))
+            else {
+                return usage(prog);
+            };
+            if !run_one(day, part, stdin, trace) {
+                return ExitCode::FAILURE;
+            }
+        }
+        [prog, "export", day, format, opts @ ..] => {
+            let stdin = match opts {
+                [] => false,
+                ["-"] => true,
+                _ => return usage(prog),
+            };
+            let format = match *format {
+                "--dot" => Export::Dot,
+                "--json" => Export::Json,
+                _ => return usage(prog),
+            };
+            let Some(day) = parse_day(day) else {
+                return usage(prog);
+            };
+            if !export(day, format, stdin) {
+                return ExitCode::FAILURE;
+            }
+        }
+        [prog, "lan", args @ ..] => {
+            let stdin = args.contains(&"-");
+            let args: Vec<&str> = args.iter().copied().filter(|a| *a != "-").collect();
+            let command = match Command::parse(&args) {
+                Ok(command) => command,
+                Err(e) => {
+                    eprintln!("error: {e}");
+                    return usage(prog);
+                }
+            };
+            if !lan(command, stdin) {
+                return ExitCode::FAILURE;
+            }
+        }
+        [prog, "bench", dir, opts @ ..] => {
+            let Some((runs, json)) = parse_bench_opts(opts) else {
+                return usage(prog);
+            };
+            if !run_bench(Path::new(dir), runs, json) {
+                return ExitCode::FAILURE;
+            }
+        }
+        [prog, ..] => return usage(prog),
+        [] => return usage("aoc"),
+    }
+    ExitCode::SUCCESS
+}
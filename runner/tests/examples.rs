@@ -0,0 +1,45 @@
+use aoc::solution::Answer;
+use aoc2024::answers::Answers;
+use aoc2024::DAYS;
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn examples() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples");
+    let answers = Answers::parse(&fs::read_to_string(dir.join("answers.txt")).unwrap()).unwrap();
+
+    let mut failures = Vec::new();
+    for (file, expected) in answers.iter() {
+        let day: usize = file[3..5].parse().unwrap();
+        let input = fs::read_to_string(dir.join(file)).unwrap();
+        let solution = &DAYS[day - 1];
+        let parsed = (solution.parse)(&input).unwrap();
+        for (part, f, expected) in [
+            (1, solution.part1, expected[0]),
+            (2, solution.part2, expected[1]),
+        ] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            let answer = f(parsed.as_ref()).to_string();
+            if answer != expected {
+                failures.push(format!(
+                    "{file} part {part}: got {answer}, expected {expected}"
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn unsolved() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples");
+    let input = fs::read_to_string(dir.join("day17a.txt")).unwrap();
+    let answer = DAYS[16].run(2, &input).unwrap();
+    assert_eq!(
+        answer,
+        Answer::Unsolved("no A outputs the program".to_string())
+    );
+}